@@ -1,5 +1,7 @@
 use crate::key::*;
 use crate::note::*;
+use crate::scale::*;
+use std::fmt::Display;
 
 #[allow(dead_code)]
 type ChordTuple<C> = (
@@ -17,66 +19,162 @@ type ChordTuple<C> = (
     <C as Chord>::M7,
 );
 
+#[allow(dead_code)]
+type ExtendedTuple<C> = (
+    <C as Chord>::A4,
+    <C as Chord>::A6,
+    <C as Chord>::d7,
+    <C as Chord>::m9,
+    <C as Chord>::M9,
+    <C as Chord>::A9,
+    <C as Chord>::P11,
+    <C as Chord>::A11,
+    <C as Chord>::m13,
+    <C as Chord>::M13,
+);
+
 #[allow(non_camel_case_types)]
 pub trait Chord: Default {
     type R: Note;
+    /// Lowest note, the root unless inverted or over a slash bass
+    type Bass: Note;
     type P1: Note;
     type m2: Note;
     type M2: Note;
     type m3: Note;
     type M3: Note;
     type P4: Note;
+    type A4: Note;
     type d5: Note;
     type P5: Note;
     type A5: Note;
     type M6: Note;
+    type A6: Note;
+    type d7: Note;
     type m7: Note;
     type M7: Note;
+    type m9: Note;
+    type M9: Note;
+    type A9: Note;
+    type P11: Note;
+    type A11: Note;
+    type m13: Note;
+    type M13: Note;
     fn name(&self) -> String;
     fn notes_tuple(&self) -> ChordTuple<Self> {
         Default::default()
     }
-    fn notes(&self) -> String {
+    /// Slots left out of `notes_tuple`: A4, A6, d7 and the tensions
+    fn extended_tuple(&self) -> ExtendedTuple<Self> {
+        Default::default()
+    }
+    fn bass(&self) -> Self::Bass {
+        Default::default()
+    }
+    fn slots(&self) -> Slots {
         [
-            Self::P1::default().name(),
-            Self::m2::default().name(),
-            Self::M2::default().name(),
-            Self::m3::default().name(),
-            Self::M3::default().name(),
-            Self::P4::default().name(),
-            Self::d5::default().name(),
-            Self::P5::default().name(),
-            Self::A5::default().name(),
-            Self::M6::default().name(),
-            Self::m7::default().name(),
-            Self::M7::default().name(),
+            Self::P1::default().pitch(),
+            Self::m2::default().pitch(),
+            Self::M2::default().pitch(),
+            Self::m3::default().pitch(),
+            Self::M3::default().pitch(),
+            Self::P4::default().pitch(),
+            Self::A4::default().pitch(),
+            Self::d5::default().pitch(),
+            Self::P5::default().pitch(),
+            Self::A5::default().pitch(),
+            Self::M6::default().pitch(),
+            Self::A6::default().pitch(),
+            Self::d7::default().pitch(),
+            Self::m7::default().pitch(),
+            Self::M7::default().pitch(),
+            Self::m9::default().pitch(),
+            Self::M9::default().pitch(),
+            Self::A9::default().pitch(),
+            Self::P11::default().pitch(),
+            Self::A11::default().pitch(),
+            Self::m13::default().pitch(),
+            Self::M13::default().pitch(),
         ]
-        .iter()
-        .filter(|s| !s.is_empty())
-        .map(|s| format!("{s:<3}"))
-        .collect::<Vec<_>>()
-        .join(" ")
+    }
+    fn notes(&self) -> String {
+        format_notes(
+            &self.slots(),
+            Self::R::default().pitch(),
+            self.bass().pitch(),
+        )
+    }
+    /// Chord tones from the root upwards, labelled by their function
+    fn tones(&self) -> Vec<ChordTone> {
+        tones(&self.slots())
+    }
+    /// Compatible scales on the root, most common first
+    fn scales(&self) -> Vec<ChordScale> {
+        chord_scales(&self.slots(), root_pitch::<Self::R>(), None)
+    }
+    /// Compatible scales on the root, those closest to `key` first
+    fn scales_in<K: Key>(&self, key: &K) -> Vec<ChordScale> {
+        chord_scales(&self.slots(), root_pitch::<Self::R>(), Some(&key.pitches()))
+    }
+    /// Runtime value of this chord, with the same quality, modifiers and bass.
+    /// A `Formula` no chord symbol spells takes the nearest one instead, with
+    /// other tones; `Formula::symbol` tells the two apart
+    fn value(&self) -> ChordValue;
+}
+
+pub(crate) fn root_pitch<R: Note>() -> Pitch {
+    R::default().pitch().expect("chord roots are notes")
+}
+
+/// Name of a chord wrapped in modifiers, in the canonical order `ChordValue::normalize` gives,
+/// so `Sus4<Omit5<MajorSeventh<C>>>` is "Cmaj7sus4omit5" however the wrappers nest
+fn modified_name(chord: &impl Chord) -> String {
+    let value = chord.value();
+    value.normalize().unwrap_or(value).name()
+}
+
+/// `name` followed by `suffix`, with an altered tone right after the root in
+/// parentheses, as "C(♯5)" since "C♯5" is a C♯ power chord
+fn append_suffix(name: String, root: &str, suffix: &str) -> String {
+    if name == root && suffix.starts_with(['♭', '♯']) {
+        format!("{name}({suffix})")
+    } else {
+        name + suffix
     }
 }
 
 pub trait TriadChord: Chord {}
 impl<R: Note> Chord for Major<R> {
     type R = <P1 as IntervalResolve<R>>::R;
+    type Bass = Self::R;
     type P1 = <P1 as IntervalResolve<R>>::R;
     type m2 = ();
     type M2 = ();
     type m3 = ();
     type M3 = <M3 as IntervalResolve<R>>::R;
     type P4 = ();
+    type A4 = ();
     type d5 = ();
     type P5 = <P5 as IntervalResolve<R>>::R;
     type A5 = ();
     type M6 = ();
+    type A6 = ();
+    type d7 = ();
     type m7 = ();
     type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
     fn name(&self) -> String {
         R::default().name()
     }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::Major)
+    }
 }
 
 #[derive(Debug, Default)]
@@ -89,70 +187,106 @@ impl<R: Note> TriadChord for Minor<R> {}
 
 impl<R: Note> Chord for Minor<R> {
     type R = R;
+    type Bass = Self::R;
     type P1 = <P1 as IntervalResolve<R>>::R;
     type m2 = ();
     type M2 = ();
     type m3 = <m3 as IntervalResolve<R>>::R;
     type M3 = ();
     type P4 = ();
+    type A4 = ();
     type d5 = ();
     type P5 = <P5 as IntervalResolve<R>>::R;
     type A5 = ();
     type M6 = ();
+    type A6 = ();
+    type d7 = ();
     type m7 = ();
     type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
     fn name(&self) -> String {
         format!("{}m", R::default().name())
     }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::Minor)
+    }
 }
 
 #[derive(Debug, Default)]
-struct Aug<R: Note>(R);
+pub struct Aug<R: Note>(R);
 
-impl<R: Note> Chord for Aug<R>
-where
-    MajorKey<R>: Key,
-{
+impl<R: Note> Chord for Aug<R> {
     type R = R;
+    type Bass = Self::R;
     type P1 = <P1 as IntervalResolve<R>>::R;
     type m2 = ();
     type M2 = ();
     type m3 = ();
     type M3 = <M3 as IntervalResolve<R>>::R;
     type P4 = ();
+    type A4 = ();
     type d5 = ();
     type P5 = ();
     type A5 = <A5 as IntervalResolve<R>>::R;
     type M6 = ();
+    type A6 = ();
+    type d7 = ();
     type m7 = ();
     type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
     fn name(&self) -> String {
         format!("{}aug", self.0.name())
     }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::Aug)
+    }
 }
 
 #[derive(Debug, Default)]
-struct Dim<R: Note>(R);
-impl<R: Note> Chord for Dim<R>
-where
-    MajorKey<R>: Key,
-{
+pub struct Dim<R: Note>(R);
+impl<R: Note> Chord for Dim<R> {
     type R = R;
+    type Bass = Self::R;
     type P1 = <P1 as IntervalResolve<R>>::R;
     type m2 = ();
     type M2 = ();
     type m3 = <m3 as IntervalResolve<R>>::R;
     type M3 = ();
     type P4 = ();
+    type A4 = ();
     type d5 = <d5 as IntervalResolve<R>>::R;
     type P5 = ();
     type A5 = ();
     type M6 = ();
+    type A6 = ();
+    type d7 = ();
     type m7 = ();
     type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
     fn name(&self) -> String {
         format!("{}dim", self.0.name())
     }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::Dim)
+    }
 }
 
 #[derive(Debug, Default)]
@@ -167,213 +301,2750 @@ where
     Major<R>: TriadChord,
 {
     type R = R;
+    type Bass = Self::R;
     type P1 = <P1 as IntervalResolve<R>>::R;
     type m2 = ();
     type M2 = ();
     type m3 = ();
     type M3 = <M3 as IntervalResolve<R>>::R;
     type P4 = ();
+    type A4 = ();
     type d5 = ();
     type P5 = <P5 as IntervalResolve<R>>::R;
     type A5 = ();
     type M6 = ();
+    type A6 = ();
+    type d7 = ();
     type m7 = <m7 as IntervalResolve<R>>::R;
     type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
     fn name(&self) -> String {
         format!("{}7", self.0.name())
     }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::Seventh)
+    }
 }
 impl<R: Note> Chord for MajorSeventh<R>
 where
     Major<R>: TriadChord,
 {
     type R = R;
+    type Bass = Self::R;
     type P1 = <P1 as IntervalResolve<R>>::R;
     type m2 = ();
     type M2 = ();
     type m3 = ();
     type M3 = <M3 as IntervalResolve<R>>::R;
     type P4 = ();
+    type A4 = ();
     type d5 = ();
     type P5 = <P5 as IntervalResolve<R>>::R;
     type A5 = ();
     type M6 = ();
+    type A6 = ();
+    type d7 = ();
     type m7 = ();
     type M7 = <M7 as IntervalResolve<R>>::R;
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
     fn name(&self) -> String {
         format!("{}maj7", self.0.name())
     }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::MajorSeventh)
+    }
 }
 impl<R: Note> Chord for MinorSeventh<R> {
     type R = R;
+    type Bass = Self::R;
     type P1 = <P1 as IntervalResolve<R>>::R;
     type m2 = ();
     type M2 = ();
     type m3 = <m3 as IntervalResolve<R>>::R;
     type M3 = ();
     type P4 = ();
+    type A4 = ();
     type d5 = ();
     type P5 = <P5 as IntervalResolve<R>>::R;
     type A5 = ();
     type M6 = ();
+    type A6 = ();
+    type d7 = ();
     type m7 = <m7 as IntervalResolve<R>>::R;
     type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
     fn name(&self) -> String {
         format!("{}m7", self.0.name())
     }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::MinorSeventh)
+    }
 }
 
 #[derive(Debug, Default)]
-pub struct Sus2<C: Chord>(C);
+pub struct HalfDiminished<R: Note>(R);
 #[derive(Debug, Default)]
-pub struct Sus4<C: Chord>(C);
+pub struct DiminishedSeventh<R: Note>(R);
+#[derive(Debug, Default)]
+pub struct MinorMajorSeventh<R: Note>(R);
+#[derive(Debug, Default)]
+pub struct AugmentedSeventh<R: Note>(R);
+#[derive(Debug, Default)]
+pub struct AugmentedMajorSeventh<R: Note>(R);
 
-impl<C: Chord> Chord for Sus2<C> {
-    type R = C::R;
-    type P1 = <P1 as IntervalResolve<C::R>>::R;
-    type m2 = C::m2;
-    type M2 = <M2 as IntervalResolve<C::R>>::R;
-    type m3 = ();
+impl<R: Note> Chord for HalfDiminished<R> {
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = <m3 as IntervalResolve<R>>::R;
     type M3 = ();
-    type P4 = C::P4;
-    type d5 = C::d5;
-    type P5 = C::P5;
-    type A5 = C::A5;
-    type M6 = C::M6;
-    type m7 = C::m7;
-    type M7 = C::M7;
+    type P4 = ();
+    type A4 = ();
+    type d5 = <d5 as IntervalResolve<R>>::R;
+    type P5 = ();
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = <m7 as IntervalResolve<R>>::R;
+    type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
     fn name(&self) -> String {
-        format!("{}sus2", self.0.name())
+        format!("{}m7♭5", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::HalfDiminished)
     }
 }
 
-impl<C: Chord> Chord for Sus4<C> {
-    type R = C::R;
-    type P1 = <P1 as IntervalResolve<C::R>>::R;
-    type m2 = C::m2;
-    type M2 = C::M2;
-    type m3 = ();
+impl<R: Note> Chord for DiminishedSeventh<R> {
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = <m3 as IntervalResolve<R>>::R;
     type M3 = ();
-    type P4 = <P4 as IntervalResolve<C::R>>::R;
-    type d5 = C::d5;
-    type P5 = C::P5;
-    type A5 = C::A5;
-    type M6 = C::M6;
-    type m7 = C::m7;
-    type M7 = C::M7;
+    type P4 = ();
+    type A4 = ();
+    type d5 = <d5 as IntervalResolve<R>>::R;
+    type P5 = ();
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = <d7 as IntervalResolve<R>>::R;
+    type m7 = ();
+    type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
     fn name(&self) -> String {
-        format!("{}sus4", self.0.name())
+        format!("{}dim7", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::DiminishedSeventh)
     }
 }
 
-#[derive(Debug, Default)]
-pub struct Omit1<C: Chord>(C);
-#[derive(Debug, Default)]
-pub struct Omit3<C: Chord>(C);
-#[derive(Debug, Default)]
-pub struct Omit5<C: Chord>(C);
-
-impl<C: Chord> Chord for Omit1<C> {
-    type R = C::R;
-    type P1 = ();
-    type m2 = C::m2;
-    type M2 = C::M2;
-    type m3 = C::m3;
-    type M3 = C::M3;
-    type P4 = C::P4;
-    type d5 = C::d5;
-    type P5 = C::P5;
-    type A5 = C::A5;
-    type M6 = C::M6;
-    type m7 = C::m7;
-    type M7 = C::M7;
+impl<R: Note> Chord for MinorMajorSeventh<R> {
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = <m3 as IntervalResolve<R>>::R;
+    type M3 = ();
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = ();
+    type M7 = <M7 as IntervalResolve<R>>::R;
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
     fn name(&self) -> String {
-        format!("{}omit1", C::default().name())
+        format!("{}mM7", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::MinorMajorSeventh)
     }
 }
-impl<C: Chord> Chord for Omit3<C> {
-    type R = C::R;
-    type P1 = C::P1;
-    type m2 = C::m2;
-    type M2 = C::M2;
+
+impl<R: Note> Chord for AugmentedSeventh<R>
+where
+    Major<R>: TriadChord,
+{
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
     type m3 = ();
-    type M3 = ();
-    type P4 = C::P4;
-    type d5 = C::d5;
-    type P5 = C::P5;
-    type A5 = C::A5;
-    type M6 = C::M6;
-    type m7 = C::m7;
-    type M7 = C::M7;
+    type M3 = <M3 as IntervalResolve<R>>::R;
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = ();
+    type A5 = <A5 as IntervalResolve<R>>::R;
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = <m7 as IntervalResolve<R>>::R;
+    type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
     fn name(&self) -> String {
-        format!("{}omit3", C::default().name())
+        format!("{}aug7", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::AugmentedSeventh)
     }
 }
-impl<C: Chord> Chord for Omit5<C> {
-    type R = C::R;
-    type P1 = C::P1;
-    type m2 = C::m2;
-    type M2 = C::M2;
-    type m3 = C::m3;
-    type M3 = C::M3;
-    type P4 = C::P4;
-    type d5 = C::d5;
+
+impl<R: Note> Chord for AugmentedMajorSeventh<R>
+where
+    Major<R>: TriadChord,
+{
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = <M3 as IntervalResolve<R>>::R;
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
     type P5 = ();
-    type A5 = C::A5;
-    type M6 = C::M6;
-    type m7 = C::m7;
-    type M7 = C::M7;
+    type A5 = <A5 as IntervalResolve<R>>::R;
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = ();
+    type M7 = <M7 as IntervalResolve<R>>::R;
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
     fn name(&self) -> String {
-        format!("{}omit5", C::default().name())
+        format!("{}augmaj7", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::AugmentedMajorSeventh)
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    #[test]
-    fn test() {
-        assert_eq!(<Major<C>>::default().name(), "C");
-        assert_eq!(<Major<D>>::default().name(), "D");
-        assert_eq!(<Major<Sharp<C>>>::default().name(), "C♯ ");
-        assert_eq!(<Major<Flat<C>>>::default().name(), "C♭ ");
-        assert_eq!(<Minor<C>>::default().name(), "Cm");
-        assert_eq!(<Minor<Sharp<C>>>::default().name(), "C♯ m");
-        assert_eq!(<Minor<Flat<C>>>::default().name(), "C♭ m");
-        <Major<C>>::default().notes_tuple();
-        <Major<B>>::default().notes_tuple();
-        <Major<C>>::default().notes_tuple();
-        <Major<B>>::default().notes_tuple();
-        <Minor<D>>::default().notes_tuple();
-        <Minor<E>>::default().notes_tuple();
-        <Minor<Flat<D>>>::default().notes_tuple();
+/// Altered dominant: no fifth, with ♭9, ♯9, ♯11 and ♭13
+#[derive(Debug, Default)]
+pub struct Altered<R: Note>(R);
 
-        <Aug<C>>::default().notes_tuple();
-        assert_eq!(<Aug<C>>::default().name(), "Caug");
+impl<R: Note> Chord for Altered<R>
+where
+    Major<R>: TriadChord,
+{
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = <M3 as IntervalResolve<R>>::R;
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = ();
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = <m7 as IntervalResolve<R>>::R;
+    type M7 = ();
+    type m9 = <m9 as IntervalResolve<R>>::R;
+    type M9 = ();
+    type A9 = <A9 as IntervalResolve<R>>::R;
+    type P11 = ();
+    type A11 = <A11 as IntervalResolve<R>>::R;
+    type m13 = <m13 as IntervalResolve<R>>::R;
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}7alt", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::Altered)
+    }
+}
 
-        <Dim<B>>::default().notes_tuple();
-        <Dim<C>>::default().notes_tuple();
-        <Dim<G>>::default().notes_tuple();
-        assert_eq!(<Dim<C>>::default().name(), "Cdim");
+#[derive(Debug, Default)]
+pub struct Sixth<R: Note>(R);
+#[derive(Debug, Default)]
+pub struct MinorSixth<R: Note>(R);
+#[derive(Debug, Default)]
+pub struct SixNine<R: Note>(R);
 
-        <Seventh<C>>::default().notes_tuple();
-        <MajorSeventh<C>>::default().notes_tuple();
-        <MinorSeventh<C>>::default().notes_tuple();
-        assert_eq!(<Seventh<C>>::default().name(), "C7");
-        assert_eq!(<MajorSeventh<C>>::default().name(), "Cmaj7");
-        assert_eq!(<MinorSeventh<C>>::default().name(), "Cm7");
+impl<R: Note> Chord for Sixth<R>
+where
+    Major<R>: TriadChord,
+{
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = <M3 as IntervalResolve<R>>::R;
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = <M6 as IntervalResolve<R>>::R;
+    type A6 = ();
+    type d7 = ();
+    type m7 = ();
+    type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}6", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::Sixth)
+    }
+}
 
-        <Sus4<Major<C>>>::default().notes_tuple();
-        <Sus4<Minor<C>>>::default().notes_tuple();
+impl<R: Note> Chord for MinorSixth<R> {
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = <m3 as IntervalResolve<R>>::R;
+    type M3 = ();
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = <M6 as IntervalResolve<R>>::R;
+    type A6 = ();
+    type d7 = ();
+    type m7 = ();
+    type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}m6", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::MinorSixth)
+    }
+}
+
+impl<R: Note> Chord for SixNine<R>
+where
+    Major<R>: TriadChord,
+{
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = <M3 as IntervalResolve<R>>::R;
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = <M6 as IntervalResolve<R>>::R;
+    type A6 = ();
+    type d7 = ();
+    type m7 = ();
+    type M7 = ();
+    type m9 = ();
+    type M9 = <M9 as IntervalResolve<R>>::R;
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}6/9", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::SixNine)
+    }
+}
+
+// Eleventh omits the major third and Thirteenth and MajorThirteenth the eleventh, which clash
+#[derive(Debug, Default)]
+pub struct Ninth<R: Note>(R);
+#[derive(Debug, Default)]
+pub struct MajorNinth<R: Note>(R);
+#[derive(Debug, Default)]
+pub struct MinorNinth<R: Note>(R);
+#[derive(Debug, Default)]
+pub struct Eleventh<R: Note>(R);
+#[derive(Debug, Default)]
+pub struct MinorEleventh<R: Note>(R);
+#[derive(Debug, Default)]
+pub struct Thirteenth<R: Note>(R);
+#[derive(Debug, Default)]
+pub struct MajorThirteenth<R: Note>(R);
+#[derive(Debug, Default)]
+pub struct MinorThirteenth<R: Note>(R);
+
+impl<R: Note> Chord for Ninth<R>
+where
+    Major<R>: TriadChord,
+{
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = <M3 as IntervalResolve<R>>::R;
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = <m7 as IntervalResolve<R>>::R;
+    type M7 = ();
+    type m9 = ();
+    type M9 = <M9 as IntervalResolve<R>>::R;
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}9", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::Ninth)
+    }
+}
+
+impl<R: Note> Chord for MajorNinth<R>
+where
+    Major<R>: TriadChord,
+{
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = <M3 as IntervalResolve<R>>::R;
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = ();
+    type M7 = <M7 as IntervalResolve<R>>::R;
+    type m9 = ();
+    type M9 = <M9 as IntervalResolve<R>>::R;
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}maj9", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::MajorNinth)
+    }
+}
+
+impl<R: Note> Chord for MinorNinth<R> {
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = <m3 as IntervalResolve<R>>::R;
+    type M3 = ();
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = <m7 as IntervalResolve<R>>::R;
+    type M7 = ();
+    type m9 = ();
+    type M9 = <M9 as IntervalResolve<R>>::R;
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}m9", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::MinorNinth)
+    }
+}
+
+impl<R: Note> Chord for Eleventh<R>
+where
+    Major<R>: TriadChord,
+{
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = ();
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = <m7 as IntervalResolve<R>>::R;
+    type M7 = ();
+    type m9 = ();
+    type M9 = <M9 as IntervalResolve<R>>::R;
+    type A9 = ();
+    type P11 = <P11 as IntervalResolve<R>>::R;
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}11", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::Eleventh)
+    }
+}
+
+impl<R: Note> Chord for MinorEleventh<R> {
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = <m3 as IntervalResolve<R>>::R;
+    type M3 = ();
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = <m7 as IntervalResolve<R>>::R;
+    type M7 = ();
+    type m9 = ();
+    type M9 = <M9 as IntervalResolve<R>>::R;
+    type A9 = ();
+    type P11 = <P11 as IntervalResolve<R>>::R;
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}m11", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::MinorEleventh)
+    }
+}
+
+impl<R: Note> Chord for Thirteenth<R>
+where
+    Major<R>: TriadChord,
+{
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = <M3 as IntervalResolve<R>>::R;
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = <m7 as IntervalResolve<R>>::R;
+    type M7 = ();
+    type m9 = ();
+    type M9 = <M9 as IntervalResolve<R>>::R;
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = <M13 as IntervalResolve<R>>::R;
+    fn name(&self) -> String {
+        format!("{}13", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::Thirteenth)
+    }
+}
+
+impl<R: Note> Chord for MajorThirteenth<R>
+where
+    Major<R>: TriadChord,
+{
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = <M3 as IntervalResolve<R>>::R;
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = ();
+    type M7 = <M7 as IntervalResolve<R>>::R;
+    type m9 = ();
+    type M9 = <M9 as IntervalResolve<R>>::R;
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = <M13 as IntervalResolve<R>>::R;
+    fn name(&self) -> String {
+        format!("{}maj13", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::MajorThirteenth)
+    }
+}
+
+impl<R: Note> Chord for MinorThirteenth<R> {
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = <m3 as IntervalResolve<R>>::R;
+    type M3 = ();
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = <m7 as IntervalResolve<R>>::R;
+    type M7 = ();
+    type m9 = ();
+    type M9 = <M9 as IntervalResolve<R>>::R;
+    type A9 = ();
+    type P11 = <P11 as IntervalResolve<R>>::R;
+    type A11 = ();
+    type m13 = ();
+    type M13 = <M13 as IntervalResolve<R>>::R;
+    fn name(&self) -> String {
+        format!("{}m13", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::MinorThirteenth)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SeventhFlatNinth<R: Note>(R);
+#[derive(Debug, Default)]
+pub struct SeventhSharpNinth<R: Note>(R);
+#[derive(Debug, Default)]
+pub struct SeventhSharpEleventh<R: Note>(R);
+#[derive(Debug, Default)]
+pub struct SeventhFlatThirteenth<R: Note>(R);
+
+impl<R: Note> Chord for SeventhFlatNinth<R>
+where
+    Major<R>: TriadChord,
+{
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = <M3 as IntervalResolve<R>>::R;
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = <m7 as IntervalResolve<R>>::R;
+    type M7 = ();
+    type m9 = <m9 as IntervalResolve<R>>::R;
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}7♭9", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::SeventhFlatNinth)
+    }
+}
+
+impl<R: Note> Chord for SeventhSharpNinth<R>
+where
+    Major<R>: TriadChord,
+{
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = <M3 as IntervalResolve<R>>::R;
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = <m7 as IntervalResolve<R>>::R;
+    type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = <A9 as IntervalResolve<R>>::R;
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}7♯9", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::SeventhSharpNinth)
+    }
+}
+
+impl<R: Note> Chord for SeventhSharpEleventh<R>
+where
+    Major<R>: TriadChord,
+{
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = <M3 as IntervalResolve<R>>::R;
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = <m7 as IntervalResolve<R>>::R;
+    type M7 = ();
+    type m9 = ();
+    type M9 = <M9 as IntervalResolve<R>>::R;
+    type A9 = ();
+    type P11 = ();
+    type A11 = <A11 as IntervalResolve<R>>::R;
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}7♯11", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::SeventhSharpEleventh)
+    }
+}
+
+impl<R: Note> Chord for SeventhFlatThirteenth<R>
+where
+    Major<R>: TriadChord,
+{
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = <M3 as IntervalResolve<R>>::R;
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = <m7 as IntervalResolve<R>>::R;
+    type M7 = ();
+    type m9 = ();
+    type M9 = <M9 as IntervalResolve<R>>::R;
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = <m13 as IntervalResolve<R>>::R;
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}7♭13", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::SeventhFlatThirteenth)
+    }
+}
+
+/// Root and fifth only
+#[derive(Debug, Default)]
+pub struct Power<R: Note>(R);
+/// Two stacked perfect fourths
+#[derive(Debug, Default)]
+pub struct Quartal<R: Note>(R);
+/// Three stacked fourths topped by a major third, as in Bill Evans' voicing on "So What"
+#[derive(Debug, Default)]
+pub struct SoWhat<R: Note>(R);
+/// Two stacked perfect fifths
+#[derive(Debug, Default)]
+pub struct Quintal<R: Note>(R);
+/// Three adjacent semitones
+#[derive(Debug, Default)]
+pub struct Cluster<R: Note>(R);
+
+impl<R: Note> Chord for Power<R> {
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = ();
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = ();
+    type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}5", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::Power)
+    }
+}
+impl<R: Note> Chord for Quartal<R> {
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = ();
+    type P4 = <P4 as IntervalResolve<R>>::R;
+    type A4 = ();
+    type d5 = ();
+    type P5 = ();
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = <m7 as IntervalResolve<R>>::R;
+    type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}quartal", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::Quartal)
+    }
+}
+impl<R: Note> Chord for SoWhat<R> {
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = <m3 as IntervalResolve<R>>::R;
+    type M3 = ();
+    type P4 = <P4 as IntervalResolve<R>>::R;
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = <m7 as IntervalResolve<R>>::R;
+    type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}SoWhat", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::SoWhat)
+    }
+}
+impl<R: Note> Chord for Quintal<R> {
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = ();
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<R>>::R;
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = ();
+    type M7 = ();
+    type m9 = ();
+    type M9 = <M9 as IntervalResolve<R>>::R;
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}quintal", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::Quintal)
+    }
+}
+impl<R: Note> Chord for Cluster<R> {
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = <m2 as IntervalResolve<R>>::R;
+    type M2 = <M2 as IntervalResolve<R>>::R;
+    type m3 = ();
+    type M3 = ();
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = ();
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = ();
+    type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}cluster", self.0.name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::Cluster)
+    }
+}
+
+/// Augmented sixth chords of the key `K`, on its ♭6 with the ♯4 as augmented sixth
+#[derive(Debug, Default)]
+pub struct ItalianSixth<K: Key>(K);
+#[derive(Debug, Default)]
+pub struct FrenchSixth<K: Key>(K);
+#[derive(Debug, Default)]
+pub struct GermanSixth<K: Key>(K);
+
+impl<K: Key> Chord for ItalianSixth<K> {
+    type R = <m6 as IntervalResolve<K::I>>::R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<Self::R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = <M3 as IntervalResolve<Self::R>>::R;
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = ();
+    type A5 = ();
+    type M6 = ();
+    type A6 = <A6 as IntervalResolve<Self::R>>::R;
+    type d7 = ();
+    type m7 = ();
+    type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}It+6", Self::R::default().name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::ItalianSixth)
+    }
+}
+impl<K: Key> Chord for FrenchSixth<K> {
+    type R = <m6 as IntervalResolve<K::I>>::R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<Self::R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = <M3 as IntervalResolve<Self::R>>::R;
+    type P4 = ();
+    type A4 = <A4 as IntervalResolve<Self::R>>::R;
+    type d5 = ();
+    type P5 = ();
+    type A5 = ();
+    type M6 = ();
+    type A6 = <A6 as IntervalResolve<Self::R>>::R;
+    type d7 = ();
+    type m7 = ();
+    type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}Fr+6", Self::R::default().name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::FrenchSixth)
+    }
+}
+impl<K: Key> Chord for GermanSixth<K> {
+    type R = <m6 as IntervalResolve<K::I>>::R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<Self::R>>::R;
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = <M3 as IntervalResolve<Self::R>>::R;
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = <P5 as IntervalResolve<Self::R>>::R;
+    type A5 = ();
+    type M6 = ();
+    type A6 = <A6 as IntervalResolve<Self::R>>::R;
+    type d7 = ();
+    type m7 = ();
+    type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+    fn name(&self) -> String {
+        format!("{}Ger+6", Self::R::default().name())
+    }
+    fn value(&self) -> ChordValue {
+        ChordValue::new(root_pitch::<Self::R>(), Quality::GermanSixth)
+    }
+}
+
+// Suspensions replace a major third, so `Sus4<Minor<_>>` and `Sus2<Sus4<_>>` are not chords
+#[derive(Debug, Default)]
+pub struct Sus2<C: Chord>(C);
+#[derive(Debug, Default)]
+pub struct Sus4<C: Chord>(C);
+
+impl<C: Chord> Chord for Sus2<C>
+where
+    C::M3: Pitched,
+{
+    type R = C::R;
+    type Bass = C::Bass;
+    type P1 = <P1 as IntervalResolve<C::R>>::R;
+    type m2 = C::m2;
+    type M2 = <M2 as IntervalResolve<C::R>>::R;
+    type m3 = ();
+    type M3 = ();
+    type P4 = C::P4;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = C::P5;
+    type A5 = C::A5;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = C::M9;
+    type A9 = C::A9;
+    type P11 = C::P11;
+    type A11 = C::A11;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        modified_name(self)
+    }
+    fn value(&self) -> ChordValue {
+        self.0.value().with(Modifier::Sus2)
+    }
+}
+
+impl<C: Chord> Chord for Sus4<C>
+where
+    C::M3: Pitched,
+{
+    type R = C::R;
+    type Bass = C::Bass;
+    type P1 = <P1 as IntervalResolve<C::R>>::R;
+    type m2 = C::m2;
+    type M2 = C::M2;
+    type m3 = ();
+    type M3 = ();
+    type P4 = <P4 as IntervalResolve<C::R>>::R;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = C::P5;
+    type A5 = C::A5;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = C::M9;
+    type A9 = C::A9;
+    type P11 = C::P11;
+    type A11 = C::A11;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        modified_name(self)
+    }
+    fn value(&self) -> ChordValue {
+        self.0.value().with(Modifier::Sus4)
+    }
+}
+
+// Only tones present can be omitted, so `Omit3<Sus4<_>>` is not a chord
+#[derive(Debug, Default)]
+pub struct Omit1<C: Chord>(C);
+#[derive(Debug, Default)]
+pub struct Omit3<C: Chord>(C);
+#[derive(Debug, Default)]
+pub struct Omit5<C: Chord>(C);
+
+impl<C: Chord> Chord for Omit1<C>
+where
+    C::P1: Pitched,
+{
+    type R = C::R;
+    type Bass = C::Bass;
+    type P1 = ();
+    type m2 = C::m2;
+    type M2 = C::M2;
+    type m3 = C::m3;
+    type M3 = C::M3;
+    type P4 = C::P4;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = C::P5;
+    type A5 = C::A5;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = C::M9;
+    type A9 = C::A9;
+    type P11 = C::P11;
+    type A11 = C::A11;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        modified_name(self)
+    }
+    fn value(&self) -> ChordValue {
+        self.0.value().with(Modifier::Omit1)
+    }
+}
+impl<C: Chord> Chord for Omit3<C>
+where
+    C::M3: OrElse<C::m3>,
+    <C::M3 as OrElse<C::m3>>::R: Pitched,
+{
+    type R = C::R;
+    type Bass = C::Bass;
+    type P1 = C::P1;
+    type m2 = C::m2;
+    type M2 = C::M2;
+    type m3 = ();
+    type M3 = ();
+    type P4 = C::P4;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = C::P5;
+    type A5 = C::A5;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = C::M9;
+    type A9 = C::A9;
+    type P11 = C::P11;
+    type A11 = C::A11;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        modified_name(self)
+    }
+    fn value(&self) -> ChordValue {
+        self.0.value().with(Modifier::Omit3)
+    }
+}
+impl<C: Chord> Chord for Omit5<C>
+where
+    C::P5: Pitched,
+{
+    type R = C::R;
+    type Bass = C::Bass;
+    type P1 = C::P1;
+    type m2 = C::m2;
+    type M2 = C::M2;
+    type m3 = C::m3;
+    type M3 = C::M3;
+    type P4 = C::P4;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = ();
+    type A5 = C::A5;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = C::M9;
+    type A9 = C::A9;
+    type P11 = C::P11;
+    type A11 = C::A11;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        modified_name(self)
+    }
+    fn value(&self) -> ChordValue {
+        self.0.value().with(Modifier::Omit5)
+    }
+}
+
+// Only tones missing can be added, so `Add9<Ninth<_>>` is not a chord
+#[derive(Debug, Default)]
+pub struct Add2<C: Chord>(C);
+#[derive(Debug, Default)]
+pub struct Add4<C: Chord>(C);
+#[derive(Debug, Default)]
+pub struct Add6<C: Chord>(C);
+#[derive(Debug, Default)]
+pub struct Add9<C: Chord>(C);
+#[derive(Debug, Default)]
+pub struct Add11<C: Chord>(C);
+
+impl<C: Chord> Chord for Add2<C>
+where
+    C::M2: Unpitched,
+{
+    type R = C::R;
+    type Bass = C::Bass;
+    type P1 = C::P1;
+    type m2 = C::m2;
+    type M2 = <M2 as IntervalResolve<C::R>>::R;
+    type m3 = C::m3;
+    type M3 = C::M3;
+    type P4 = C::P4;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = C::P5;
+    type A5 = C::A5;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = C::M9;
+    type A9 = C::A9;
+    type P11 = C::P11;
+    type A11 = C::A11;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        modified_name(self)
+    }
+    fn value(&self) -> ChordValue {
+        self.0.value().with(Modifier::Add2)
+    }
+}
+impl<C: Chord> Chord for Add4<C>
+where
+    C::P4: Unpitched,
+{
+    type R = C::R;
+    type Bass = C::Bass;
+    type P1 = C::P1;
+    type m2 = C::m2;
+    type M2 = C::M2;
+    type m3 = C::m3;
+    type M3 = C::M3;
+    type P4 = <P4 as IntervalResolve<C::R>>::R;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = C::P5;
+    type A5 = C::A5;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = C::M9;
+    type A9 = C::A9;
+    type P11 = C::P11;
+    type A11 = C::A11;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        modified_name(self)
+    }
+    fn value(&self) -> ChordValue {
+        self.0.value().with(Modifier::Add4)
+    }
+}
+impl<C: Chord> Chord for Add6<C>
+where
+    C::M6: Unpitched,
+{
+    type R = C::R;
+    type Bass = C::Bass;
+    type P1 = C::P1;
+    type m2 = C::m2;
+    type M2 = C::M2;
+    type m3 = C::m3;
+    type M3 = C::M3;
+    type P4 = C::P4;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = C::P5;
+    type A5 = C::A5;
+    type M6 = <M6 as IntervalResolve<C::R>>::R;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = C::M9;
+    type A9 = C::A9;
+    type P11 = C::P11;
+    type A11 = C::A11;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        modified_name(self)
+    }
+    fn value(&self) -> ChordValue {
+        self.0.value().with(Modifier::Add6)
+    }
+}
+impl<C: Chord> Chord for Add9<C>
+where
+    C::M9: Unpitched,
+{
+    type R = C::R;
+    type Bass = C::Bass;
+    type P1 = C::P1;
+    type m2 = C::m2;
+    type M2 = C::M2;
+    type m3 = C::m3;
+    type M3 = C::M3;
+    type P4 = C::P4;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = C::P5;
+    type A5 = C::A5;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = <M9 as IntervalResolve<C::R>>::R;
+    type A9 = C::A9;
+    type P11 = C::P11;
+    type A11 = C::A11;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        modified_name(self)
+    }
+    fn value(&self) -> ChordValue {
+        self.0.value().with(Modifier::Add9)
+    }
+}
+impl<C: Chord> Chord for Add11<C>
+where
+    C::P11: Unpitched,
+{
+    type R = C::R;
+    type Bass = C::Bass;
+    type P1 = C::P1;
+    type m2 = C::m2;
+    type M2 = C::M2;
+    type m3 = C::m3;
+    type M3 = C::M3;
+    type P4 = C::P4;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = C::P5;
+    type A5 = C::A5;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = C::M9;
+    type A9 = C::A9;
+    type P11 = <P11 as IntervalResolve<C::R>>::R;
+    type A11 = C::A11;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        modified_name(self)
+    }
+    fn value(&self) -> ChordValue {
+        self.0.value().with(Modifier::Add11)
+    }
+}
+
+// Each alteration replaces only the natural tone, so `Sharp9<Flat9<_>>` keeps both ninths,
+// but a tone is altered once: `Flat5<Sharp5<_>>` and `Flat9<Flat9<_>>` are not chords
+#[derive(Debug, Default)]
+pub struct Flat5<C: Chord>(C);
+#[derive(Debug, Default)]
+pub struct Sharp5<C: Chord>(C);
+#[derive(Debug, Default)]
+pub struct Flat9<C: Chord>(C);
+#[derive(Debug, Default)]
+pub struct Sharp9<C: Chord>(C);
+#[derive(Debug, Default)]
+pub struct Sharp11<C: Chord>(C);
+#[derive(Debug, Default)]
+pub struct Flat13<C: Chord>(C);
+
+impl<C: Chord> Chord for Flat5<C>
+where
+    C::P5: Pitched,
+    C::d5: Unpitched,
+{
+    type R = C::R;
+    type Bass = C::Bass;
+    type P1 = C::P1;
+    type m2 = C::m2;
+    type M2 = C::M2;
+    type m3 = C::m3;
+    type M3 = C::M3;
+    type P4 = C::P4;
+    type A4 = C::A4;
+    type d5 = <d5 as IntervalResolve<C::R>>::R;
+    type P5 = ();
+    type A5 = C::A5;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = C::M9;
+    type A9 = C::A9;
+    type P11 = C::P11;
+    type A11 = C::A11;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        modified_name(self)
+    }
+    fn value(&self) -> ChordValue {
+        self.0.value().with(Modifier::Flat5)
+    }
+}
+impl<C: Chord> Chord for Sharp5<C>
+where
+    C::P5: Pitched,
+    C::A5: Unpitched,
+{
+    type R = C::R;
+    type Bass = C::Bass;
+    type P1 = C::P1;
+    type m2 = C::m2;
+    type M2 = C::M2;
+    type m3 = C::m3;
+    type M3 = C::M3;
+    type P4 = C::P4;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = ();
+    type A5 = <A5 as IntervalResolve<C::R>>::R;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = C::M9;
+    type A9 = C::A9;
+    type P11 = C::P11;
+    type A11 = C::A11;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        modified_name(self)
+    }
+    fn value(&self) -> ChordValue {
+        self.0.value().with(Modifier::Sharp5)
+    }
+}
+impl<C: Chord> Chord for Flat9<C>
+where
+    C::m9: Unpitched,
+{
+    type R = C::R;
+    type Bass = C::Bass;
+    type P1 = C::P1;
+    type m2 = C::m2;
+    type M2 = C::M2;
+    type m3 = C::m3;
+    type M3 = C::M3;
+    type P4 = C::P4;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = C::P5;
+    type A5 = C::A5;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = <m9 as IntervalResolve<C::R>>::R;
+    type M9 = ();
+    type A9 = C::A9;
+    type P11 = C::P11;
+    type A11 = C::A11;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        modified_name(self)
+    }
+    fn value(&self) -> ChordValue {
+        self.0.value().with(Modifier::Flat9)
+    }
+}
+impl<C: Chord> Chord for Sharp9<C>
+where
+    C::A9: Unpitched,
+{
+    type R = C::R;
+    type Bass = C::Bass;
+    type P1 = C::P1;
+    type m2 = C::m2;
+    type M2 = C::M2;
+    type m3 = C::m3;
+    type M3 = C::M3;
+    type P4 = C::P4;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = C::P5;
+    type A5 = C::A5;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = ();
+    type A9 = <A9 as IntervalResolve<C::R>>::R;
+    type P11 = C::P11;
+    type A11 = C::A11;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        modified_name(self)
+    }
+    fn value(&self) -> ChordValue {
+        self.0.value().with(Modifier::Sharp9)
+    }
+}
+impl<C: Chord> Chord for Sharp11<C>
+where
+    C::A11: Unpitched,
+{
+    type R = C::R;
+    type Bass = C::Bass;
+    type P1 = C::P1;
+    type m2 = C::m2;
+    type M2 = C::M2;
+    type m3 = C::m3;
+    type M3 = C::M3;
+    type P4 = C::P4;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = C::P5;
+    type A5 = C::A5;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = C::M9;
+    type A9 = C::A9;
+    type P11 = ();
+    type A11 = <A11 as IntervalResolve<C::R>>::R;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        modified_name(self)
+    }
+    fn value(&self) -> ChordValue {
+        self.0.value().with(Modifier::Sharp11)
+    }
+}
+impl<C: Chord> Chord for Flat13<C>
+where
+    C::m13: Unpitched,
+{
+    type R = C::R;
+    type Bass = C::Bass;
+    type P1 = C::P1;
+    type m2 = C::m2;
+    type M2 = C::M2;
+    type m3 = C::m3;
+    type M3 = C::M3;
+    type P4 = C::P4;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = C::P5;
+    type A5 = C::A5;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = C::M9;
+    type A9 = C::A9;
+    type P11 = C::P11;
+    type A11 = C::A11;
+    type m13 = <m13 as IntervalResolve<C::R>>::R;
+    type M13 = ();
+    fn name(&self) -> String {
+        modified_name(self)
+    }
+    fn value(&self) -> ChordValue {
+        self.0.value().with(Modifier::Flat13)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Slash<C: Chord, Bass: Note>(C, Bass);
+
+impl<C: Chord, N: Pitched> Chord for Slash<C, N> {
+    type R = C::R;
+    type Bass = N::R;
+    type P1 = C::P1;
+    type m2 = C::m2;
+    type M2 = C::M2;
+    type m3 = C::m3;
+    type M3 = C::M3;
+    type P4 = C::P4;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = C::P5;
+    type A5 = C::A5;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = C::M9;
+    type A9 = C::A9;
+    type P11 = C::P11;
+    type A11 = C::A11;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        // A bass on the root is no slash, as in `value`
+        if root_pitch::<N>() == root_pitch::<C::R>() {
+            return self.0.name();
+        }
+        format!("{}/{}", self.0.name(), self.1.name())
+    }
+    fn value(&self) -> ChordValue {
+        let chord = self.0.value();
+        match root_pitch::<N>() {
+            bass if bass == chord.root => chord,
+            bass => chord.over(bass),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct First;
+#[derive(Debug, Default)]
+pub struct Second;
+#[derive(Debug, Default)]
+pub struct Third;
+
+/// Chord tone of `C` moved to the bass by this inversion
+pub trait InversionOf<C: Chord>: Default {
+    type Bass: Note;
+}
+impl<C: Chord> InversionOf<C> for First
+where
+    C::m3: OrElse<C::M3>,
+    <C::m3 as OrElse<C::M3>>::R: OrElse<C::P4>,
+    <<C::m3 as OrElse<C::M3>>::R as OrElse<C::P4>>::R: OrElse<C::M2>,
+{
+    type Bass = <<<C::m3 as OrElse<C::M3>>::R as OrElse<C::P4>>::R as OrElse<C::M2>>::R;
+}
+impl<C: Chord> InversionOf<C> for Second
+where
+    C::d5: OrElse<C::P5>,
+    <C::d5 as OrElse<C::P5>>::R: OrElse<C::A5>,
+{
+    type Bass = <<C::d5 as OrElse<C::P5>>::R as OrElse<C::A5>>::R;
+}
+impl<C: Chord> InversionOf<C> for Third
+where
+    C::d7: OrElse<C::m7>,
+    <C::d7 as OrElse<C::m7>>::R: OrElse<C::M7>,
+    <<C::d7 as OrElse<C::m7>>::R as OrElse<C::M7>>::R: OrElse<C::M6>,
+{
+    type Bass = <<<C::d7 as OrElse<C::m7>>::R as OrElse<C::M7>>::R as OrElse<C::M6>>::R;
+}
+
+#[derive(Debug, Default)]
+pub struct Inversion<C: Chord, N: InversionOf<C>>(C, N);
+
+impl<C: Chord, N: InversionOf<C>> Chord for Inversion<C, N>
+where
+    N::Bass: Pitched,
+{
+    type R = C::R;
+    type Bass = N::Bass;
+    type P1 = C::P1;
+    type m2 = C::m2;
+    type M2 = C::M2;
+    type m3 = C::m3;
+    type M3 = C::M3;
+    type P4 = C::P4;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = C::P5;
+    type A5 = C::A5;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = C::M9;
+    type A9 = C::A9;
+    type P11 = C::P11;
+    type A11 = C::A11;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        format!("{}/{}", self.0.name(), self.bass().name())
+    }
+    fn value(&self) -> ChordValue {
+        self.0.value().over(root_pitch::<N::Bass>())
+    }
+}
+
+macro_rules! impl_transpose_for_root {
+    ($($t:ident),+) => {
+        $(impl<R: Note, I: IntervalResolve<R>> TransposeBy<I> for $t<R>
+        where
+            $t<I::R>: Chord,
+        {
+            type Output = $t<I::R>;
+        })+
+    };
+}
+
+impl_transpose_for_root!(
+    Major,
+    Minor,
+    Aug,
+    Dim,
+    Seventh,
+    MajorSeventh,
+    MinorSeventh,
+    HalfDiminished,
+    DiminishedSeventh,
+    MinorMajorSeventh,
+    AugmentedSeventh,
+    AugmentedMajorSeventh,
+    Sixth,
+    MinorSixth,
+    SixNine,
+    Ninth,
+    MajorNinth,
+    MinorNinth,
+    Eleventh,
+    MinorEleventh,
+    Thirteenth,
+    MajorThirteenth,
+    MinorThirteenth,
+    SeventhFlatNinth,
+    SeventhSharpNinth,
+    SeventhSharpEleventh,
+    SeventhFlatThirteenth,
+    Altered,
+    Power,
+    Quartal,
+    SoWhat,
+    Quintal,
+    Cluster
+);
+
+macro_rules! impl_transpose_for_key_chord {
+    ($($t:ident),+) => {
+        $(impl<K, I> TransposeBy<I> for $t<K>
+        where
+            K: Key + TransposeBy<I>,
+            K::Output: Key,
+        {
+            type Output = $t<K::Output>;
+        })+
+    };
+}
+
+impl_transpose_for_key_chord!(ItalianSixth, FrenchSixth, GermanSixth);
+
+macro_rules! impl_transpose_for_wrapper {
+    ($($t:ident),+) => {
+        $(impl<C, I> TransposeBy<I> for $t<C>
+        where
+            C: Chord + TransposeBy<I>,
+            C::Output: Chord,
+        {
+            type Output = $t<C::Output>;
+        })+
+    };
+}
+
+impl_transpose_for_wrapper!(
+    Sus2, Sus4, Omit1, Omit3, Omit5, Add2, Add4, Add6, Add9, Add11, Flat5, Sharp5, Flat9, Sharp9,
+    Sharp11, Flat13
+);
+
+impl<C, N, I> TransposeBy<I> for Slash<C, N>
+where
+    C: Chord + TransposeBy<I>,
+    C::Output: Chord,
+    N: Note,
+    I: IntervalResolve<N>,
+{
+    type Output = Slash<C::Output, I::R>;
+}
+
+impl<C, N, I> TransposeBy<I> for Inversion<C, N>
+where
+    C: Chord + TransposeBy<I>,
+    C::Output: Chord,
+    N: InversionOf<C> + InversionOf<C::Output>,
+{
+    type Output = Inversion<C::Output, N>;
+}
+
+/// Runtime counterpart of the `Chord` slots, in the same order
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Slot {
+    P1,
+    m2,
+    M2,
+    m3,
+    M3,
+    P4,
+    A4,
+    d5,
+    P5,
+    A5,
+    M6,
+    A6,
+    d7,
+    m7,
+    M7,
+    m9,
+    M9,
+    A9,
+    P11,
+    A11,
+    m13,
+    M13,
+}
+
+/// Pitches of every `Slot`, `None` where the chord has no tone
+pub type Slots = [Option<Pitch>; 22];
+
+impl Slot {
+    pub const ALL: [Slot; 22] = [
+        Slot::P1,
+        Slot::m2,
+        Slot::M2,
+        Slot::m3,
+        Slot::M3,
+        Slot::P4,
+        Slot::A4,
+        Slot::d5,
+        Slot::P5,
+        Slot::A5,
+        Slot::M6,
+        Slot::A6,
+        Slot::d7,
+        Slot::m7,
+        Slot::M7,
+        Slot::m9,
+        Slot::M9,
+        Slot::A9,
+        Slot::P11,
+        Slot::A11,
+        Slot::m13,
+        Slot::M13,
+    ];
+
+    /// Letter steps and semitones above the root, within one octave
+    pub fn interval(&self) -> (usize, usize) {
+        match self {
+            Slot::P1 => (0, 0),
+            Slot::m2 => (1, 1),
+            Slot::M2 => (1, 2),
+            Slot::m3 => (2, 3),
+            Slot::M3 => (2, 4),
+            Slot::P4 => (3, 5),
+            Slot::A4 => (3, 6),
+            Slot::d5 => (4, 6),
+            Slot::P5 => (4, 7),
+            Slot::A5 => (4, 8),
+            Slot::M6 => (5, 9),
+            Slot::A6 => (5, 10),
+            Slot::d7 => (6, 9),
+            Slot::m7 => (6, 10),
+            Slot::M7 => (6, 11),
+            Slot::m9 => (1, 1),
+            Slot::M9 => (1, 2),
+            Slot::A9 => (1, 3),
+            Slot::P11 => (3, 5),
+            Slot::A11 => (3, 6),
+            Slot::m13 => (5, 8),
+            Slot::M13 => (5, 9),
+        }
+    }
+
+    /// Slot at letter steps and semitones above the root, preferring the tensions above the octave
+    pub fn of(interval: (usize, usize)) -> Option<Slot> {
+        const TENSIONS: [Slot; 7] = [
+            Slot::m9,
+            Slot::M9,
+            Slot::A9,
+            Slot::P11,
+            Slot::A11,
+            Slot::m13,
+            Slot::M13,
+        ];
+        TENSIONS
+            .iter()
+            .chain(Slot::ALL.iter())
+            .find(|slot| slot.interval() == interval)
+            .copied()
+    }
+
+    /// Ninths, elevenths and thirteenths
+    pub fn is_tension(&self) -> bool {
+        matches!(
+            self,
+            Slot::m9 | Slot::M9 | Slot::A9 | Slot::P11 | Slot::A11 | Slot::m13 | Slot::M13
+        )
+    }
+
+    /// Function of the tone above the root, such as "♭7" or "9"
+    pub fn function(&self) -> &'static str {
+        match self {
+            Slot::P1 => "1",
+            Slot::m2 => "♭2",
+            Slot::M2 => "2",
+            Slot::m3 => "♭3",
+            Slot::M3 => "3",
+            Slot::P4 => "4",
+            Slot::A4 => "♯4",
+            Slot::d5 => "♭5",
+            Slot::P5 => "5",
+            Slot::A5 => "♯5",
+            Slot::M6 => "6",
+            Slot::A6 => "♯6",
+            Slot::d7 => "𝄫7",
+            Slot::m7 => "♭7",
+            Slot::M7 => "7",
+            Slot::m9 => "♭9",
+            Slot::M9 => "9",
+            Slot::A9 => "♯9",
+            Slot::P11 => "11",
+            Slot::A11 => "♯11",
+            Slot::m13 => "♭13",
+            Slot::M13 => "13",
+        }
+    }
+
+    pub(crate) fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// Chord tone labelled by its function above the root
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChordTone {
+    pub slot: Slot,
+    pub pitch: Pitch,
+}
+
+impl ChordTone {
+    pub fn function(&self) -> &'static str {
+        self.slot.function()
+    }
+}
+
+/// The slots are already in stacking order: root, 2/3/4, 5, 6/7, then the tensions
+fn tones(slots: &Slots) -> Vec<ChordTone> {
+    Slot::ALL
+        .iter()
+        .zip(slots)
+        .filter_map(|(slot, pitch)| pitch.map(|pitch| ChordTone { slot: *slot, pitch }))
+        .collect()
+}
+
+fn format_notes(slots: &Slots, root: Option<Pitch>, bass: Option<Pitch>) -> String {
+    let mut notes = slots.iter().flatten().copied().collect::<Vec<_>>();
+    if let Some(bass) = bass.filter(|b| Some(b.id()) != root.map(|r| r.id())) {
+        match notes.iter().position(|n| n.id() == bass.id()) {
+            Some(i) => notes.rotate_left(i),
+            None => notes.insert(0, bass),
+        }
+    }
+    notes
+        .iter()
+        .map(|n| format!("{:<3}", n.name()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Runtime counterpart of the chord types above
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Quality {
+    Major,
+    Minor,
+    Aug,
+    Dim,
+    Seventh,
+    MajorSeventh,
+    MinorSeventh,
+    HalfDiminished,
+    DiminishedSeventh,
+    MinorMajorSeventh,
+    AugmentedSeventh,
+    AugmentedMajorSeventh,
+    Sixth,
+    MinorSixth,
+    SixNine,
+    Ninth,
+    MajorNinth,
+    MinorNinth,
+    Eleventh,
+    MinorEleventh,
+    Thirteenth,
+    MajorThirteenth,
+    MinorThirteenth,
+    SeventhFlatNinth,
+    SeventhSharpNinth,
+    SeventhSharpEleventh,
+    SeventhFlatThirteenth,
+    Altered,
+    ItalianSixth,
+    FrenchSixth,
+    GermanSixth,
+    Power,
+    Quartal,
+    SoWhat,
+    Quintal,
+    Cluster,
+}
+
+impl Quality {
+    pub const ALL: [Quality; 36] = [
+        Quality::Major,
+        Quality::Minor,
+        Quality::Aug,
+        Quality::Dim,
+        Quality::Seventh,
+        Quality::MajorSeventh,
+        Quality::MinorSeventh,
+        Quality::HalfDiminished,
+        Quality::DiminishedSeventh,
+        Quality::MinorMajorSeventh,
+        Quality::AugmentedSeventh,
+        Quality::AugmentedMajorSeventh,
+        Quality::Sixth,
+        Quality::MinorSixth,
+        Quality::SixNine,
+        Quality::Ninth,
+        Quality::MajorNinth,
+        Quality::MinorNinth,
+        Quality::Eleventh,
+        Quality::MinorEleventh,
+        Quality::Thirteenth,
+        Quality::MajorThirteenth,
+        Quality::MinorThirteenth,
+        Quality::SeventhFlatNinth,
+        Quality::SeventhSharpNinth,
+        Quality::SeventhSharpEleventh,
+        Quality::SeventhFlatThirteenth,
+        Quality::Altered,
+        Quality::ItalianSixth,
+        Quality::FrenchSixth,
+        Quality::GermanSixth,
+        Quality::Power,
+        Quality::Quartal,
+        Quality::SoWhat,
+        Quality::Quintal,
+        Quality::Cluster,
+    ];
+
+    pub fn slots(&self) -> &'static [Slot] {
+        match self {
+            Quality::Major => &[Slot::P1, Slot::M3, Slot::P5],
+            Quality::Minor => &[Slot::P1, Slot::m3, Slot::P5],
+            Quality::Aug => &[Slot::P1, Slot::M3, Slot::A5],
+            Quality::Dim => &[Slot::P1, Slot::m3, Slot::d5],
+            Quality::Seventh => &[Slot::P1, Slot::M3, Slot::P5, Slot::m7],
+            Quality::MajorSeventh => &[Slot::P1, Slot::M3, Slot::P5, Slot::M7],
+            Quality::MinorSeventh => &[Slot::P1, Slot::m3, Slot::P5, Slot::m7],
+            Quality::HalfDiminished => &[Slot::P1, Slot::m3, Slot::d5, Slot::m7],
+            Quality::DiminishedSeventh => &[Slot::P1, Slot::m3, Slot::d5, Slot::d7],
+            Quality::MinorMajorSeventh => &[Slot::P1, Slot::m3, Slot::P5, Slot::M7],
+            Quality::AugmentedSeventh => &[Slot::P1, Slot::M3, Slot::A5, Slot::m7],
+            Quality::AugmentedMajorSeventh => &[Slot::P1, Slot::M3, Slot::A5, Slot::M7],
+            Quality::Sixth => &[Slot::P1, Slot::M3, Slot::P5, Slot::M6],
+            Quality::MinorSixth => &[Slot::P1, Slot::m3, Slot::P5, Slot::M6],
+            Quality::SixNine => &[Slot::P1, Slot::M3, Slot::P5, Slot::M6, Slot::M9],
+            Quality::Ninth => &[Slot::P1, Slot::M3, Slot::P5, Slot::m7, Slot::M9],
+            Quality::MajorNinth => &[Slot::P1, Slot::M3, Slot::P5, Slot::M7, Slot::M9],
+            Quality::MinorNinth => &[Slot::P1, Slot::m3, Slot::P5, Slot::m7, Slot::M9],
+            Quality::Eleventh => &[Slot::P1, Slot::P5, Slot::m7, Slot::M9, Slot::P11],
+            Quality::MinorEleventh => {
+                &[Slot::P1, Slot::m3, Slot::P5, Slot::m7, Slot::M9, Slot::P11]
+            }
+            Quality::Thirteenth => &[Slot::P1, Slot::M3, Slot::P5, Slot::m7, Slot::M9, Slot::M13],
+            Quality::MajorThirteenth => {
+                &[Slot::P1, Slot::M3, Slot::P5, Slot::M7, Slot::M9, Slot::M13]
+            }
+            Quality::MinorThirteenth => &[
+                Slot::P1,
+                Slot::m3,
+                Slot::P5,
+                Slot::m7,
+                Slot::M9,
+                Slot::P11,
+                Slot::M13,
+            ],
+            Quality::SeventhFlatNinth => &[Slot::P1, Slot::M3, Slot::P5, Slot::m7, Slot::m9],
+            Quality::SeventhSharpNinth => &[Slot::P1, Slot::M3, Slot::P5, Slot::m7, Slot::A9],
+            Quality::SeventhSharpEleventh => {
+                &[Slot::P1, Slot::M3, Slot::P5, Slot::m7, Slot::M9, Slot::A11]
+            }
+            Quality::SeventhFlatThirteenth => {
+                &[Slot::P1, Slot::M3, Slot::P5, Slot::m7, Slot::M9, Slot::m13]
+            }
+            Quality::Altered => &[
+                Slot::P1,
+                Slot::M3,
+                Slot::m7,
+                Slot::m9,
+                Slot::A9,
+                Slot::A11,
+                Slot::m13,
+            ],
+            Quality::ItalianSixth => &[Slot::P1, Slot::M3, Slot::A6],
+            Quality::FrenchSixth => &[Slot::P1, Slot::M3, Slot::A4, Slot::A6],
+            Quality::GermanSixth => &[Slot::P1, Slot::M3, Slot::P5, Slot::A6],
+            Quality::Power => &[Slot::P1, Slot::P5],
+            Quality::Quartal => &[Slot::P1, Slot::P4, Slot::m7],
+            Quality::SoWhat => &[Slot::P1, Slot::m3, Slot::P4, Slot::P5, Slot::m7],
+            Quality::Quintal => &[Slot::P1, Slot::P5, Slot::M9],
+            Quality::Cluster => &[Slot::P1, Slot::m2, Slot::M2],
+        }
+    }
+
+    /// Italian, French or German sixth, named by its function in a key
+    pub fn is_augmented_sixth(&self) -> bool {
+        matches!(
+            self,
+            Quality::ItalianSixth | Quality::FrenchSixth | Quality::GermanSixth
+        )
+    }
+
+    /// Quality with a major third in place of the minor one and the same other tones
+    fn major(&self) -> Option<Quality> {
+        match self {
+            Quality::Minor => Some(Quality::Major),
+            Quality::MinorSeventh => Some(Quality::Seventh),
+            Quality::MinorMajorSeventh => Some(Quality::MajorSeventh),
+            Quality::MinorSixth => Some(Quality::Sixth),
+            Quality::MinorNinth => Some(Quality::Ninth),
+            Quality::MinorEleventh => Some(Quality::Eleventh),
+            _ => None,
+        }
+    }
+
+    /// Suffix after the root in `Chord::name`
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            Quality::Major => "",
+            Quality::Minor => "m",
+            Quality::Aug => "aug",
+            Quality::Dim => "dim",
+            Quality::Seventh => "7",
+            Quality::MajorSeventh => "maj7",
+            Quality::MinorSeventh => "m7",
+            Quality::HalfDiminished => "m7♭5",
+            Quality::DiminishedSeventh => "dim7",
+            Quality::MinorMajorSeventh => "mM7",
+            Quality::AugmentedSeventh => "aug7",
+            Quality::AugmentedMajorSeventh => "augmaj7",
+            Quality::Sixth => "6",
+            Quality::MinorSixth => "m6",
+            Quality::SixNine => "6/9",
+            Quality::Ninth => "9",
+            Quality::MajorNinth => "maj9",
+            Quality::MinorNinth => "m9",
+            Quality::Eleventh => "11",
+            Quality::MinorEleventh => "m11",
+            Quality::Thirteenth => "13",
+            Quality::MajorThirteenth => "maj13",
+            Quality::MinorThirteenth => "m13",
+            Quality::SeventhFlatNinth => "7♭9",
+            Quality::SeventhSharpNinth => "7♯9",
+            Quality::SeventhSharpEleventh => "7♯11",
+            Quality::SeventhFlatThirteenth => "7♭13",
+            Quality::Altered => "7alt",
+            Quality::ItalianSixth => "It+6",
+            Quality::FrenchSixth => "Fr+6",
+            Quality::GermanSixth => "Ger+6",
+            Quality::Power => "5",
+            Quality::Quartal => "quartal",
+            Quality::SoWhat => "SoWhat",
+            Quality::Quintal => "quintal",
+            Quality::Cluster => "cluster",
+        }
+    }
+}
+
+/// Runtime counterpart of the chord wrappers such as `Sus4` and `Omit5`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Modifier {
+    Sus2,
+    Sus4,
+    Omit1,
+    Omit3,
+    Omit5,
+    Add2,
+    Add4,
+    Add6,
+    Add9,
+    Add11,
+    Flat5,
+    Sharp5,
+    Flat9,
+    Sharp9,
+    Sharp11,
+    Flat13,
+}
+
+impl Modifier {
+    /// Suffix appended to the name of the wrapped chord
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            Modifier::Sus2 => "sus2",
+            Modifier::Sus4 => "sus4",
+            Modifier::Omit1 => "omit1",
+            Modifier::Omit3 => "omit3",
+            Modifier::Omit5 => "omit5",
+            Modifier::Add2 => "add2",
+            Modifier::Add4 => "add4",
+            Modifier::Add6 => "add6",
+            Modifier::Add9 => "add9",
+            Modifier::Add11 => "add11",
+            Modifier::Flat5 => "♭5",
+            Modifier::Sharp5 => "♯5",
+            Modifier::Flat9 => "♭9",
+            Modifier::Sharp9 => "♯9",
+            Modifier::Sharp11 => "♯11",
+            Modifier::Flat13 => "♭13",
+        }
+    }
+
+    /// Check this modifier against the slots of the chord it wraps, by the
+    /// same rules as the bounds on the wrapper types
+    fn check(&self, slots: &Slots) -> Result<(), ModifierError> {
+        let has = |slot: Slot| slots[slot.index()].is_some();
+        let (cleared, added) = self.change();
+        match self {
+            Modifier::Sus2 | Modifier::Sus4 if !has(Slot::M3) => {
+                Err(ModifierError::NoMajorThird(*self))
+            }
+            Modifier::Omit1 | Modifier::Omit3 | Modifier::Omit5
+                if !cleared.iter().any(|slot| has(*slot)) =>
+            {
+                Err(ModifierError::Missing(*self))
+            }
+            Modifier::Flat5 | Modifier::Sharp5 if !has(Slot::P5) => {
+                Err(ModifierError::Missing(*self))
+            }
+            Modifier::Sus2
+            | Modifier::Sus4
+            | Modifier::Omit1
+            | Modifier::Omit3
+            | Modifier::Omit5 => Ok(()),
+            _ if added.iter().any(|slot| has(*slot)) => Err(ModifierError::Present(*self)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Position in a canonical name: alterations, suspension, added tones, then omissions
+    fn rank(&self) -> (usize, usize) {
+        let group = match self {
+            Modifier::Flat5
+            | Modifier::Sharp5
+            | Modifier::Flat9
+            | Modifier::Sharp9
+            | Modifier::Sharp11
+            | Modifier::Flat13 => 0,
+            Modifier::Sus2 | Modifier::Sus4 => 1,
+            Modifier::Add2 | Modifier::Add4 | Modifier::Add6 | Modifier::Add9 | Modifier::Add11 => {
+                2
+            }
+            Modifier::Omit1 | Modifier::Omit3 | Modifier::Omit5 => 3,
+        };
+        (group, *self as usize)
+    }
+
+    /// Slots cleared and added by this modifier
+    fn change(&self) -> (&'static [Slot], &'static [Slot]) {
+        match self {
+            Modifier::Sus2 => (&[Slot::m3, Slot::M3], &[Slot::P1, Slot::M2]),
+            Modifier::Sus4 => (&[Slot::m3, Slot::M3], &[Slot::P1, Slot::P4]),
+            Modifier::Omit1 => (&[Slot::P1], &[]),
+            Modifier::Omit3 => (&[Slot::m3, Slot::M3], &[]),
+            Modifier::Omit5 => (&[Slot::P5], &[]),
+            Modifier::Add2 => (&[], &[Slot::M2]),
+            Modifier::Add4 => (&[], &[Slot::P4]),
+            Modifier::Add6 => (&[], &[Slot::M6]),
+            Modifier::Add9 => (&[], &[Slot::M9]),
+            Modifier::Add11 => (&[], &[Slot::P11]),
+            Modifier::Flat5 => (&[Slot::P5], &[Slot::d5]),
+            Modifier::Sharp5 => (&[Slot::P5], &[Slot::A5]),
+            Modifier::Flat9 => (&[Slot::M9], &[Slot::m9]),
+            Modifier::Sharp9 => (&[Slot::M9], &[Slot::A9]),
+            Modifier::Sharp11 => (&[Slot::P11], &[Slot::A11]),
+            Modifier::Flat13 => (&[Slot::M13], &[Slot::m13]),
+        }
+    }
+}
+
+/// Modifier that does not apply to the chord it wraps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModifierError {
+    /// Suspensions replace a major third, as in "Cmsus4" or "Csus4sus2"
+    NoMajorThird(Modifier),
+    /// The omitted or altered tone is not in the chord, as in "Csus4omit3" or "C7♯5♭5"
+    Missing(Modifier),
+    /// The added or altered tone is already in the chord, as in "C9add9" or "C7♭9♭9"
+    Present(Modifier),
+}
+
+impl Display for ModifierError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModifierError::NoMajorThird(m) => write!(f, "{} needs a major third", m.suffix()),
+            ModifierError::Missing(m) => {
+                write!(f, "{} changes a tone not in the chord", m.suffix())
+            }
+            ModifierError::Present(m) => {
+                write!(f, "{} adds a tone already in the chord", m.suffix())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ModifierError {}
+
+/// Chord built at runtime, e.g. parsed from a chord symbol
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChordValue {
+    pub root: Pitch,
+    pub quality: Quality,
+    /// Applied in order, innermost wrapper first
+    pub modifiers: Vec<Modifier>,
+    /// Slash bass, if other than the root
+    pub bass: Option<Pitch>,
+}
+
+impl ChordValue {
+    pub fn new(root: Pitch, quality: Quality) -> Self {
+        Self {
+            root,
+            quality,
+            modifiers: vec![],
+            bass: None,
+        }
+    }
+
+    pub fn with(mut self, modifier: Modifier) -> Self {
+        self.modifiers.push(modifier);
+        self
+    }
+
+    pub fn over(mut self, bass: Pitch) -> Self {
+        self.bass = Some(bass);
+        self
+    }
+
+    pub fn bass(&self) -> Pitch {
+        self.bass.unwrap_or(self.root)
+    }
+
+    /// Move the chord up by `interval`, keeping the letter distance exact
+    pub fn transpose(&self, interval: &impl Interval) -> Self {
+        self.transpose_by(interval.steps(), interval.semitones())
+    }
+
+    fn transpose_by(&self, steps: usize, semitones: usize) -> Self {
+        Self {
+            root: self.root.up(steps, semitones),
+            bass: self.bass.map(|b| b.up(steps, semitones)),
+            ..self.clone()
+        }
+    }
+
+    /// Move the chord by `semitones`, spelling the new root with as few
+    /// accidentals as possible, e.g. B♭ up a semitone is B rather than C♭
+    pub fn transpose_semitones(&self, semitones: i32) -> Self {
+        let semitones = semitones.rem_euclid(12) as usize;
+        // Usual interval for each semitone count, then its enharmonic neighbours
+        let steps = [0, 1, 1, 2, 2, 3, 3, 4, 5, 5, 6, 6][semitones];
+        [steps, (steps + 6) % 7, (steps + 1) % 7]
+            .into_iter()
+            .map(|steps| self.transpose_by(steps, semitones))
+            .min_by_key(|chord| chord.root.accidental.abs())
+            .expect("there are three candidate spellings")
+    }
+
+    pub fn name(&self) -> String {
+        let root = self.root.name();
+        let mut name = format!("{root}{}", self.quality.suffix());
+        for modifier in &self.modifiers {
+            name = append_suffix(name, &root, modifier.suffix());
+        }
+        if let Some(bass) = self.bass {
+            name += &format!("/{}", bass.name());
+        }
+        name
+    }
+
+    /// Check each modifier against the chord it wraps, innermost first, by the
+    /// rules the wrapper types enforce at compile time
+    pub fn validate(&self) -> Result<(), ModifierError> {
+        let mut chord = Self {
+            modifiers: vec![],
+            ..self.clone()
+        };
+        for modifier in &self.modifiers {
+            modifier.check(&chord.slots())?;
+            chord.modifiers.push(*modifier);
+        }
+        Ok(())
+    }
+
+    /// Valid chord with the same tones under its canonical name: root, quality,
+    /// alterations, suspension, added tones, omissions, then the slash bass,
+    /// as in "C7♭9sus4add6omit5/B♭".
+    ///
+    /// A suspension also replaces a minor third, so "Cmsus4" becomes "Csus4",
+    /// and suspending a chord without a third adds the tone, so "Csus4sus2"
+    /// becomes "Csus4add2". Omitting a missing tone, adding a present one or altering
+    /// a tone twice is dropped.
+    /// Suspensions of chords without a major counterpart, such as "Cdimsus4", are errors.
+    pub fn normalize(&self) -> Result<Self, ModifierError> {
+        let mut chord = Self {
+            modifiers: vec![],
+            ..self.clone()
+        };
+        for modifier in &self.modifiers {
+            let slots = chord.slots();
+            let modifier = match modifier.check(&slots) {
+                Ok(()) => *modifier,
+                Err(ModifierError::NoMajorThird(_)) if slots[Slot::m3.index()].is_some() => {
+                    chord.quality = chord
+                        .quality
+                        .major()
+                        .ok_or(ModifierError::NoMajorThird(*modifier))?;
+                    *modifier
+                }
+                Err(ModifierError::NoMajorThird(_)) => {
+                    let add = match modifier {
+                        Modifier::Sus2 => Modifier::Add2,
+                        _ => Modifier::Add4,
+                    };
+                    match add.check(&slots) {
+                        Ok(()) => add,
+                        Err(_) => continue,
+                    }
+                }
+                Err(_) => continue,
+            };
+            chord.modifiers.push(modifier);
+        }
+        // Omitting the root before a suspension, which restores it, is left in place
+        let mut sorted = chord.clone();
+        sorted.modifiers.sort_by_key(Modifier::rank);
+        if sorted.slots() == chord.slots() {
+            chord = sorted;
+        }
+        Ok(chord)
+    }
+
+    pub fn slots(&self) -> Slots {
+        let mut slots: Slots = Default::default();
+        let tone = |slot: &Slot| match slot {
+            // The minor seventh flattened on its own letter, as the d7 interval is
+            Slot::d7 => Some(self.root.up(6, 10).flat()),
+            _ => {
+                let (steps, semitones) = slot.interval();
+                Some(self.root.up(steps, semitones))
+            }
+        };
+        for slot in self.quality.slots() {
+            slots[slot.index()] = tone(slot);
+        }
+        for modifier in &self.modifiers {
+            let (cleared, added) = modifier.change();
+            for slot in cleared {
+                slots[slot.index()] = None;
+            }
+            for slot in added {
+                slots[slot.index()] = tone(slot);
+            }
+        }
+        slots
+    }
+
+    pub fn notes(&self) -> String {
+        format_notes(&self.slots(), Some(self.root), self.bass)
+    }
+
+    /// Chord tones from the root upwards, labelled by their function
+    pub fn tones(&self) -> Vec<ChordTone> {
+        tones(&self.slots())
+    }
+
+    /// Compatible scales on the root, most common first
+    pub fn scales(&self) -> Vec<ChordScale> {
+        chord_scales(&self.slots(), self.root, None)
+    }
+
+    /// Compatible scales on the root, those closest to `key` first
+    pub fn scales_in<K: Key>(&self, key: &K) -> Vec<ChordScale> {
+        chord_scales(&self.slots(), self.root, Some(&key.pitches()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test() {
+        assert_eq!(<Major<C>>::default().name(), "C");
+        assert_eq!(<Major<D>>::default().name(), "D");
+        assert_eq!(<Major<Sharp<C>>>::default().name(), "C♯ ");
+        assert_eq!(<Major<Flat<C>>>::default().name(), "C♭ ");
+        assert_eq!(<Minor<C>>::default().name(), "Cm");
+        assert_eq!(<Minor<Sharp<C>>>::default().name(), "C♯ m");
+        assert_eq!(<Minor<Flat<C>>>::default().name(), "C♭ m");
+        <Major<C>>::default().notes_tuple();
+        <Major<B>>::default().notes_tuple();
+        <Major<C>>::default().notes_tuple();
+        <Major<B>>::default().notes_tuple();
+        <Minor<D>>::default().notes_tuple();
+        <Minor<E>>::default().notes_tuple();
+        <Minor<Flat<D>>>::default().notes_tuple();
+
+        <Aug<C>>::default().notes_tuple();
+        assert_eq!(<Aug<C>>::default().name(), "Caug");
+
+        <Dim<B>>::default().notes_tuple();
+        <Dim<C>>::default().notes_tuple();
+        <Dim<G>>::default().notes_tuple();
+        assert_eq!(<Dim<C>>::default().name(), "Cdim");
+
+        <Seventh<C>>::default().notes_tuple();
+        <MajorSeventh<C>>::default().notes_tuple();
+        <MinorSeventh<C>>::default().notes_tuple();
+        assert_eq!(<Seventh<C>>::default().name(), "C7");
+        assert_eq!(<MajorSeventh<C>>::default().name(), "Cmaj7");
+        assert_eq!(<MinorSeventh<C>>::default().name(), "Cm7");
+
+        <Sus4<Major<C>>>::default().notes_tuple();
+        <Sus4<Sixth<C>>>::default().notes_tuple();
         <Sus4<Seventh<C>>>::default().notes_tuple();
         <Sus2<Major<C>>>::default().notes_tuple();
-        <Sus2<Minor<C>>>::default().notes_tuple();
+        <Sus2<Sixth<C>>>::default().notes_tuple();
         <Sus2<Seventh<C>>>::default().notes_tuple();
         assert_eq!(<Sus4<Major<C>>>::default().name(), "Csus4");
-        assert_eq!(<Sus4<Minor<C>>>::default().name(), "Cmsus4");
+        assert_eq!(<Sus4<Sixth<C>>>::default().name(), "C6sus4");
         assert_eq!(<Sus4<Seventh<C>>>::default().name(), "C7sus4");
         assert_eq!(<Sus4<MajorSeventh<C>>>::default().name(), "Cmaj7sus4");
-        assert_eq!(<Sus4<MinorSeventh<C>>>::default().name(), "Cm7sus4");
+        assert_eq!(<Sus4<Ninth<C>>>::default().name(), "C9sus4");
         assert_eq!(<Sus2<Major<C>>>::default().name(), "Csus2");
 
         <Omit1<Major<C>>>::default().notes_tuple();
@@ -385,4 +3056,425 @@ mod test {
             "Cmaj7sus4omit5"
         );
     }
+
+    #[test]
+    fn test_extended() {
+        <Ninth<C>>::default().notes_tuple();
+        <MinorEleventh<Sharp<F>>>::default().notes_tuple();
+        <Thirteenth<Flat<B>>>::default().notes_tuple();
+        <Thirteenth<Flat<B>>>::default().extended_tuple();
+        assert_eq!(<Ninth<C>>::default().name(), "C9");
+        assert_eq!(<MajorNinth<C>>::default().name(), "Cmaj9");
+        assert_eq!(<MinorNinth<C>>::default().name(), "Cm9");
+        assert_eq!(<Eleventh<C>>::default().name(), "C11");
+        assert_eq!(<MinorEleventh<C>>::default().name(), "Cm11");
+        assert_eq!(<Thirteenth<C>>::default().name(), "C13");
+        assert_eq!(<MajorThirteenth<C>>::default().name(), "Cmaj13");
+        assert_eq!(<MinorThirteenth<C>>::default().name(), "Cm13");
+        assert_eq!(<SeventhFlatNinth<C>>::default().name(), "C7♭9");
+        assert_eq!(<SeventhSharpNinth<C>>::default().name(), "C7♯9");
+        assert_eq!(<SeventhSharpEleventh<C>>::default().name(), "C7♯11");
+        assert_eq!(<SeventhFlatThirteenth<C>>::default().name(), "C7♭13");
+
+        assert_eq!(<Ninth<C>>::default().notes(), "C   E   G   B♭  D  ");
+        assert_eq!(<MajorNinth<F>>::default().notes(), "F   A   C   E   G  ");
+        assert_eq!(<Eleventh<C>>::default().notes(), "C   G   B♭  D   F  ");
+        assert_eq!(
+            <MinorEleventh<D>>::default().notes(),
+            "D   F   A   C   E   G  "
+        );
+        assert_eq!(
+            <Thirteenth<G>>::default().notes(),
+            "G   B   D   F   A   E  "
+        );
+        assert_eq!(
+            <SeventhFlatNinth<G>>::default().notes(),
+            "G   B   D   F   A♭ "
+        );
+        assert_eq!(
+            <SeventhSharpNinth<C>>::default().notes(),
+            "C   E   G   B♭  D♯ "
+        );
+        assert_eq!(
+            <SeventhSharpEleventh<C>>::default().notes(),
+            "C   E   G   B♭  D   F♯ "
+        );
+        assert_eq!(
+            <SeventhFlatThirteenth<C>>::default().notes(),
+            "C   E   G   B♭  D   A♭ "
+        );
+        assert_eq!(<Sus4<Ninth<C>>>::default().notes(), "C   F   G   B♭  D  ");
+    }
+
+    #[test]
+    fn test_sixth() {
+        <Sixth<C>>::default().notes_tuple();
+        <MinorSixth<Flat<E>>>::default().notes_tuple();
+        <SixNine<F>>::default().notes_tuple();
+        assert_eq!(<Sixth<C>>::default().name(), "C6");
+        assert_eq!(<MinorSixth<C>>::default().name(), "Cm6");
+        assert_eq!(<SixNine<C>>::default().name(), "C6/9");
+        assert_eq!(<Sus4<Sixth<C>>>::default().name(), "C6sus4");
+        assert_eq!(<Omit5<SixNine<C>>>::default().name(), "C6/9omit5");
+
+        assert_eq!(<Sixth<C>>::default().notes(), "C   E   G   A  ");
+        assert_eq!(<MinorSixth<D>>::default().notes(), "D   F   A   B  ");
+        assert_eq!(<SixNine<C>>::default().notes(), "C   E   G   A   D  ");
+        assert_eq!(<Sus2<Sixth<C>>>::default().notes(), "C   D   G   A  ");
+        assert_eq!(<Omit5<SixNine<C>>>::default().notes(), "C   E   A   D  ");
+    }
+
+    #[test]
+    fn test_seventh_family() {
+        <HalfDiminished<B>>::default().notes_tuple();
+        <DiminishedSeventh<Sharp<G>>>::default().notes_tuple();
+        <MinorMajorSeventh<C>>::default().notes_tuple();
+        <AugmentedSeventh<C>>::default().notes_tuple();
+        <AugmentedMajorSeventh<C>>::default().notes_tuple();
+        assert_eq!(<HalfDiminished<C>>::default().name(), "Cm7♭5");
+        assert_eq!(<DiminishedSeventh<C>>::default().name(), "Cdim7");
+        assert_eq!(<MinorMajorSeventh<C>>::default().name(), "CmM7");
+        assert_eq!(<AugmentedSeventh<C>>::default().name(), "Caug7");
+        assert_eq!(<AugmentedMajorSeventh<C>>::default().name(), "Caugmaj7");
+
+        assert_eq!(<HalfDiminished<D>>::default().notes(), "D   F   A♭  C  ");
+        assert_eq!(<HalfDiminished<B>>::default().notes(), "B   D   F   A  ");
+        assert_eq!(<DiminishedSeventh<B>>::default().notes(), "B   D   F   A♭ ");
+        assert_eq!(<DiminishedSeventh<C>>::default().notes(), "C   E♭  G♭  B𝄫 ");
+        assert_eq!(<DiminishedSeventh<F>>::default().notes(), "F   A♭  C♭  E𝄫 ");
+        assert_eq!(
+            <DiminishedSeventh<Sharp<G>>>::default().notes(),
+            "G♯  B   D   F  "
+        );
+        assert_eq!(<MinorMajorSeventh<C>>::default().notes(), "C   E♭  G   B  ");
+        assert_eq!(<AugmentedSeventh<C>>::default().notes(), "C   E   G♯  B♭ ");
+        assert_eq!(
+            <AugmentedMajorSeventh<C>>::default().notes(),
+            "C   E   G♯  B  "
+        );
+        // d7 is a separate slot from M6, spelled with a double flat where needed
+        <<DiminishedSeventh<B> as Chord>::d7>::default();
+        let _: Flat<Flat<B>> = <<DiminishedSeventh<C> as Chord>::d7>::default();
+        assert_eq!(
+            <DiminishedSeventh<C>>::default().slots(),
+            <DiminishedSeventh<C>>::default().value().slots()
+        );
+        let _: () = <<DiminishedSeventh<B> as Chord>::M6>::default();
+    }
+
+    #[test]
+    fn test_slash() {
+        <Slash<Major<C>, E>>::default().notes_tuple();
+        <Inversion<MinorSeventh<A>, Third>>::default().notes_tuple();
+        assert_eq!(<Slash<Major<C>, E>>::default().name(), "C/E");
+        assert_eq!(<Slash<MinorSeventh<A>, G>>::default().name(), "Am7/G");
+        assert_eq!(<Slash<Major<C>, E>>::default().bass().name(), "E");
+        assert_eq!(<Slash<Major<C>, E>>::default().notes(), "E   G   C  ");
+        assert_eq!(<Slash<Major<C>, D>>::default().notes(), "D   C   E   G  ");
+        assert_eq!(
+            <Slash<MinorSeventh<A>, G>>::default().notes(),
+            "G   A   C   E  "
+        );
+
+        assert_eq!(<Inversion<Major<C>, First>>::default().name(), "C/E");
+        assert_eq!(<Inversion<Minor<A>, First>>::default().name(), "Am/C");
+        assert_eq!(<Inversion<Major<C>, Second>>::default().name(), "C/G");
+        assert_eq!(<Inversion<Seventh<G>, Third>>::default().name(), "G7/F");
+        assert_eq!(
+            <Inversion<Sus4<Major<D>>, First>>::default().name(),
+            "Dsus4/G"
+        );
+        assert_eq!(
+            <Inversion<Major<C>, Second>>::default().notes(),
+            "G   C   E  "
+        );
+        assert_eq!(
+            <Inversion<Seventh<G>, Third>>::default().notes(),
+            "F   G   B   D  "
+        );
+        assert_eq!(<Inversion<Dim<B>, First>>::default().bass().name(), "D");
+    }
+
+    #[test]
+    fn test_transpose() {
+        let _: Sus4<Seventh<E>> = <Transpose<Sus4<Seventh<D>>, M2>>::default();
+        let _: Slash<Major<D>, Sharp<F>> = <Transpose<Slash<Major<C>, E>, M2>>::default();
+        let _: Inversion<Minor<B>, First> = <Transpose<Inversion<Minor<A>, First>, M2>>::default();
+        assert_eq!(
+            <Transpose<HalfDiminished<D>, P5>>::default().name(),
+            "Am7♭5"
+        );
+        assert_eq!(
+            <Transpose<Omit5<Ninth<G>>, m3>>::default().name(),
+            "B♭ 9omit5"
+        );
+        assert_eq!(
+            <Transpose<Dim<B>, m2>>::default().notes(),
+            <Dim<C>>::default().notes()
+        );
+
+        let chord = <Sus4<Seventh<D>>>::default().value();
+        assert_eq!(chord.transpose(&M2), <Sus4<Seventh<E>>>::default().value());
+        let chord = <Slash<Major<C>, E>>::default().value();
+        assert_eq!(chord.transpose(&M2).name(), "D/F♯ ");
+        assert_eq!(chord.transpose(&m3).name(), "E♭ /G");
+
+        let chord = <MinorSeventh<Flat<B>>>::default().value();
+        assert_eq!(chord.transpose_semitones(1).name(), "Bm7");
+        assert_eq!(chord.transpose_semitones(-3).name(), "Gm7");
+        assert_eq!(chord.transpose_semitones(2).name(), "Cm7");
+        let chord = <Slash<Seventh<A>, Sharp<C>>>::default().value();
+        assert_eq!(chord.transpose_semitones(1).name(), "B♭ 7/D");
+        assert_eq!(chord.transpose_semitones(-3).name(), "F♯ 7/A♯ ");
+    }
+
+    #[test]
+    fn test_add() {
+        <Add9<Major<C>>>::default().notes_tuple();
+        <Add11<Minor<A>>>::default().notes_tuple();
+        assert_eq!(<Add9<Major<C>>>::default().name(), "Cadd9");
+        assert_eq!(<Omit5<Add9<Minor<C>>>>::default().name(), "Cmadd9omit5");
+        assert_eq!(<Add2<Major<G>>>::default().name(), "Gadd2");
+        assert_eq!(<Add4<Major<C>>>::default().name(), "Cadd4");
+        assert_eq!(<Add6<Minor<D>>>::default().name(), "Dmadd6");
+        assert_eq!(<Add11<Minor<A>>>::default().name(), "Amadd11");
+
+        assert_eq!(<Add9<Major<C>>>::default().notes(), "C   E   G   D  ");
+        assert_eq!(<Add2<Major<C>>>::default().notes(), "C   D   E   G  ");
+        assert_eq!(<Add4<Major<C>>>::default().notes(), "C   E   F   G  ");
+        assert_eq!(<Add6<Major<C>>>::default().notes(), "C   E   G   A  ");
+        assert_eq!(<Add11<Minor<A>>>::default().notes(), "A   C   E   D  ");
+        assert_eq!(<Omit5<Add9<Minor<C>>>>::default().notes(), "C   E♭  D  ");
+        // No seventh is implied
+        let _: () = <<Add9<Major<C>> as Chord>::m7>::default();
+    }
+
+    #[test]
+    fn test_altered() {
+        <Flat5<Seventh<C>>>::default().notes_tuple();
+        <Flat13<Sharp9<Seventh<G>>>>::default().notes_tuple();
+        assert_eq!(<Flat5<Seventh<C>>>::default().name(), "C7♭5");
+        assert_eq!(<Sharp5<Seventh<C>>>::default().name(), "C7♯5");
+        assert_eq!(<Flat13<Sharp9<Seventh<G>>>>::default().name(), "G7♯9♭13");
+        assert_eq!(<Sharp11<MajorNinth<F>>>::default().name(), "Fmaj9♯11");
+        assert_eq!(<Flat9<Sus4<Seventh<E>>>>::default().name(), "E7♭9sus4");
+        // Type-level names follow the order of `ChordValue::normalize`
+        assert_eq!(
+            <Sus4<Omit5<MajorSeventh<C>>>>::default().name(),
+            "Cmaj7sus4omit5"
+        );
+        assert_eq!(
+            <Sharp9<Flat9<Seventh<C>>>>::default().name(),
+            <Flat9<Sharp9<Seventh<C>>>>::default().name()
+        );
+        assert_eq!(<Altered<G>>::default().name(), "G7alt");
+        // Right after the root, alterations are parenthesised to keep them apart from accidentals
+        assert_eq!(<Sharp5<Major<C>>>::default().name(), "C(♯5)");
+        assert_eq!(<Flat5<Major<Sharp<F>>>>::default().name(), "F♯ (♭5)");
+        assert_eq!(<Sharp5<Minor<C>>>::default().name(), "Cm♯5");
+
+        assert_eq!(<Flat5<Seventh<C>>>::default().notes(), "C   E   G♭  B♭ ");
+        assert_eq!(
+            <Sharp5<Major<C>>>::default().notes(),
+            <Aug<C>>::default().notes()
+        );
+        assert_eq!(<Flat9<Ninth<C>>>::default().notes(), "C   E   G   B♭  D♭ ");
+        assert_eq!(
+            <Flat13<Sharp9<Seventh<G>>>>::default().notes(),
+            "G   B   D   F   A♯  E♭ "
+        );
+        assert_eq!(
+            <Sharp9<Flat9<Seventh<G>>>>::default().notes(),
+            "G   B   D   F   A♭  A♯ "
+        );
+        assert_eq!(
+            <Sharp11<MajorNinth<F>>>::default().notes(),
+            "F   A   C   E   G   B  "
+        );
+        assert_eq!(
+            <Altered<G>>::default().notes(),
+            "G   B   F   A♭  A♯  C♯  E♭ "
+        );
+    }
+
+    fn labelled(tones: Vec<ChordTone>) -> Vec<(String, &'static str)> {
+        tones
+            .iter()
+            .map(|t| (t.pitch.name(), t.function()))
+            .collect()
+    }
+
+    #[test]
+    fn test_tones() {
+        assert_eq!(
+            labelled(<Seventh<G>>::default().tones()),
+            [
+                ("G".into(), "1"),
+                ("B".into(), "3"),
+                ("D".into(), "5"),
+                ("F".into(), "♭7")
+            ]
+        );
+        assert_eq!(
+            labelled(<Flat13<Sharp9<Seventh<G>>>>::default().tones()),
+            [
+                ("G".into(), "1"),
+                ("B".into(), "3"),
+                ("D".into(), "5"),
+                ("F".into(), "♭7"),
+                ("A♯ ".into(), "♯9"),
+                ("E♭ ".into(), "♭13"),
+            ]
+        );
+        assert_eq!(
+            labelled(<Sus4<Flat5<Seventh<E>>>>::default().tones()),
+            [
+                ("E".into(), "1"),
+                ("A".into(), "4"),
+                ("B♭ ".into(), "♭5"),
+                ("D".into(), "♭7")
+            ]
+        );
+        assert_eq!(
+            labelled(<DiminishedSeventh<B>>::default().tones())[3],
+            ("A♭ ".into(), "𝄫7")
+        );
+        let chord = <Inversion<Sixth<C>, First>>::default();
+        assert_eq!(chord.tones(), chord.value().tones());
+        assert_eq!(chord.tones()[0].slot, Slot::P1);
+    }
+
+    #[test]
+    fn test_augmented_sixth() {
+        let chord = <GermanSixth<MajorKey<C>>>::default();
+        assert_eq!(chord.name(), "A♭ Ger+6");
+        assert_eq!(chord.notes(), "A♭  C   E♭  F♯ ");
+        assert_eq!(chord.tones()[3].function(), "♯6");
+        assert_eq!(chord.value().name(), chord.name());
+        assert_eq!(
+            <FrenchSixth<MajorKey<C>>>::default().notes(),
+            "A♭  C   D   F♯ "
+        );
+        assert_eq!(
+            <FrenchSixth<MajorKey<C>>>::default().tones()[2].function(),
+            "♯4"
+        );
+        assert_eq!(
+            <ItalianSixth<MinorKey<A>>>::default().notes(),
+            "F   A   D♯ "
+        );
+        assert_eq!(
+            <Transpose<ItalianSixth<MajorKey<C>>, M2>>::default().name(),
+            "B♭ It+6"
+        );
+        let _: () = <<GermanSixth<MajorKey<C>> as Chord>::m7>::default();
+    }
+
+    #[test]
+    fn test_non_tertian() {
+        assert_eq!(<Power<C>>::default().name(), "C5");
+        assert_eq!(<Power<Flat<B>>>::default().notes(), "B♭  F  ");
+        assert_eq!(<Quartal<D>>::default().notes(), "D   G   C  ");
+        assert_eq!(<SoWhat<E>>::default().notes(), "E   G   A   B   D  ");
+        assert_eq!(<Quintal<C>>::default().notes(), "C   G   D  ");
+        assert_eq!(<Cluster<E>>::default().notes(), "E   F   F♯ ");
+        let _: Power<A> = <Transpose<Power<E>, P4>>::default();
+        for chord in ["C5", "Dquartal", "ESoWhat", "Cquintal", "Ccluster"] {
+            assert_eq!(crate::symbol::parse_chord(chord).unwrap().name(), chord);
+        }
+        assert_eq!(<SoWhat<E>>::default().value().quality, Quality::SoWhat);
+    }
+
+    #[test]
+    fn test_validate() {
+        let parse = |symbol| crate::symbol::parse_chord(symbol).unwrap();
+        for symbol in [
+            "Csus4",
+            "C7♭9sus4",
+            "Cmaj7sus4omit5",
+            "Dsus4add2",
+            "Cmadd9omit5",
+        ] {
+            assert_eq!(parse(symbol).validate(), Ok(()));
+        }
+        let errors = [
+            ("Cmsus4", ModifierError::NoMajorThird(Modifier::Sus4)),
+            ("Csus4sus2", ModifierError::NoMajorThird(Modifier::Sus2)),
+            ("C5sus4", ModifierError::NoMajorThird(Modifier::Sus4)),
+            ("Csus4omit3", ModifierError::Missing(Modifier::Omit3)),
+            ("C7♭5omit5", ModifierError::Missing(Modifier::Omit5)),
+            ("C9add9", ModifierError::Present(Modifier::Add9)),
+            ("C7♯5♭5", ModifierError::Missing(Modifier::Flat5)),
+            ("C7♭9♭9", ModifierError::Present(Modifier::Flat9)),
+            ("Caug♯5", ModifierError::Missing(Modifier::Sharp5)),
+            ("Csus2add2", ModifierError::Present(Modifier::Add2)),
+        ];
+        for (symbol, error) in errors {
+            assert_eq!(parse(symbol).validate(), Err(error), "{symbol}");
+        }
+        assert_eq!(
+            ModifierError::NoMajorThird(Modifier::Sus4).to_string(),
+            "sus4 needs a major third"
+        );
+        // Chord types enforce the same rules, so their values are always valid
+        assert_eq!(
+            <Omit5<Sus4<MajorSeventh<C>>>>::default().value().validate(),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        let parse = |symbol| crate::symbol::parse_chord(symbol).unwrap();
+        let normalized = [
+            ("Cmsus4", "Csus4"),
+            ("Cm7sus2", "C7sus2"),
+            ("Csus4sus2", "Csus4add2"),
+            ("C5sus4", "C5add4"),
+            ("Csus4omit3", "Csus4"),
+            ("C9add9", "C9"),
+            ("Cmaj7omit5sus4", "Cmaj7sus4omit5"),
+            ("Cadd9♭5", "C(♭5)add9"),
+            ("C7sus4♭9omit5/B♭", "C7♭9sus4omit5/B♭ "),
+            ("Comit1sus4", "Comit1sus4"),
+        ];
+        for (symbol, name) in normalized {
+            let chord = parse(symbol);
+            let normal = chord.normalize().unwrap();
+            assert_eq!(normal.name(), name, "{symbol}");
+            assert_eq!(normal.slots(), chord.slots(), "{symbol}");
+            assert_eq!(normal.validate(), Ok(()), "{symbol}");
+        }
+        assert_eq!(
+            parse("Cdimsus4").normalize(),
+            Err(ModifierError::NoMajorThird(Modifier::Sus4))
+        );
+    }
+
+    #[test]
+    fn test_value() {
+        fn agrees(chord: impl Chord) {
+            let value = chord.value();
+            assert_eq!(value.name(), chord.name());
+            assert_eq!(value.slots(), chord.slots());
+            assert_eq!(value.notes(), chord.notes());
+        }
+        agrees(<Major<C>>::default());
+        agrees(<Omit5<Sus4<MajorSeventh<C>>>>::default());
+        agrees(<Sharp5<Major<C>>>::default());
+        agrees(<Flat13<Sharp9<Seventh<G>>>>::default());
+        agrees(<Slash<MinorSeventh<A>, G>>::default());
+        agrees(<Inversion<Seventh<G>, Third>>::default());
+        agrees(<GermanSixth<MajorKey<C>>>::default());
+        assert_eq!(
+            <Sharp5<Major<C>>>::default().value(),
+            ChordValue::new(C.pitch().unwrap(), Quality::Major).with(Modifier::Sharp5)
+        );
+        assert_eq!(<Slash<Major<C>, C>>::default().value().bass, None);
+        assert_eq!(<Slash<Major<C>, C>>::default().name(), "C");
+        assert_eq!(
+            <Slash<Seventh<G>, G>>::default().name(),
+            <Slash<Seventh<G>, G>>::default().value().name()
+        );
+    }
 }
@@ -0,0 +1,630 @@
+use crate::chord::*;
+use crate::note::*;
+use crate::poly::*;
+use std::marker::PhantomData;
+
+/// Comparison of the pitches two chords sound, by pitch class, so enharmonic
+/// spellings match. Chord types, runtime chords and polychords compare with each other.
+///
+/// Chord types also compare at the type level, as bounds that hold only when the
+/// relation does: see `SubsetOf`, `SupersetOf`, `EnharmonicTo` and `CommonTones`.
+pub trait Compare {
+    /// Chord tones from the root upwards, after a slash bass outside them
+    fn pitches(&self) -> Vec<Pitch>;
+
+    /// Tones also in `other`, e.g. C, E and G between Cmaj7 and Am7
+    fn common_tones(&self, other: &impl Compare) -> Vec<Pitch> {
+        let other = pitch_classes(&other.pitches());
+        self.pitches()
+            .into_iter()
+            .filter(|p| other[p.id()])
+            .collect()
+    }
+
+    fn common_tone_count(&self, other: &impl Compare) -> usize {
+        self.common_tones(other).len()
+    }
+
+    /// Same pitch classes, however spelled, e.g. C6 and Am7
+    fn is_enharmonic(&self, other: &impl Compare) -> bool {
+        pitch_classes(&self.pitches()) == pitch_classes(&other.pitches())
+    }
+
+    /// Every tone is also in `other`, e.g. Cmaj7 in Cmaj9
+    fn is_subset_of(&self, other: &impl Compare) -> bool {
+        let other = pitch_classes(&other.pitches());
+        self.pitches().iter().all(|p| other[p.id()])
+    }
+
+    fn is_superset_of(&self, other: &impl Compare) -> bool {
+        let ours = pitch_classes(&self.pitches());
+        other.pitches().iter().all(|p| ours[p.id()])
+    }
+
+    /// Tones only in this chord, then those only in `other`
+    fn symmetric_difference(&self, other: &impl Compare) -> Vec<Pitch> {
+        let (ours, theirs) = (self.pitches(), other.pitches());
+        let (in_ours, in_theirs) = (pitch_classes(&ours), pitch_classes(&theirs));
+        ours.into_iter()
+            .filter(|p| !in_theirs[p.id()])
+            .chain(theirs.into_iter().filter(|p| !in_ours[p.id()]))
+            .collect()
+    }
+}
+
+fn pitch_classes(pitches: &[Pitch]) -> [bool; 12] {
+    let mut set = [false; 12];
+    for pitch in pitches {
+        set[pitch.id()] = true;
+    }
+    set
+}
+
+fn sounding(slots: &Slots, bass: Option<Pitch>) -> Vec<Pitch> {
+    let mut pitches: Vec<Pitch> = slots.iter().flatten().copied().collect();
+    if let Some(bass) = bass.filter(|b| pitches.iter().all(|p| p.id() != b.id())) {
+        pitches.insert(0, bass);
+    }
+    pitches
+}
+
+impl Compare for ChordValue {
+    fn pitches(&self) -> Vec<Pitch> {
+        sounding(&self.slots(), self.bass)
+    }
+}
+
+impl<C: Chord> Compare for C {
+    fn pitches(&self) -> Vec<Pitch> {
+        let root = C::R::default().pitch();
+        // The bass of an unslashed chord is its root, even with the root omitted
+        let bass = self.bass().pitch().filter(|b| Some(*b) != root);
+        sounding(&self.slots(), bass)
+    }
+}
+
+/// Tones of the lower chord, then those of the upper chord not already sounding
+impl Compare for PolyValue {
+    fn pitches(&self) -> Vec<Pitch> {
+        let mut pitches = self.lower.pitches();
+        for pitch in self.upper.pitches() {
+            if pitches.iter().all(|p| p.id() != pitch.id()) {
+                pitches.push(pitch);
+            }
+        }
+        pitches
+    }
+}
+
+impl<Upper: Chord, Lower: Chord> Compare for Poly<Upper, Lower> {
+    fn pitches(&self) -> Vec<Pitch> {
+        self.value().pitches()
+    }
+}
+
+/// Type-level boolean, one per pitch class of a `ClassSet`
+pub trait Bool {
+    type Not: Bool;
+    type And<B: Bool>: Bool;
+    type Or<B: Bool>: Bool;
+    /// `N`, plus one if true
+    type Plus<N: Number>: Number;
+}
+
+#[derive(Debug, Default)]
+pub struct True;
+#[derive(Debug, Default)]
+pub struct False;
+
+impl Bool for True {
+    type Not = False;
+    type And<B: Bool> = B;
+    type Or<B: Bool> = True;
+    type Plus<N: Number> = Succ<N>;
+}
+
+impl Bool for False {
+    type Not = True;
+    type And<B: Bool> = False;
+    type Or<B: Bool> = B;
+    type Plus<N: Number> = N;
+}
+
+/// Type-level count, e.g. `Succ<Succ<Zero>>` is 2
+pub trait Number {
+    const VALUE: usize;
+}
+
+#[derive(Debug, Default)]
+pub struct Zero;
+#[derive(Debug, Default)]
+pub struct Succ<N: Number>(PhantomData<N>);
+
+impl Number for Zero {
+    const VALUE: usize = 0;
+}
+
+impl<N: Number> Number for Succ<N> {
+    const VALUE: usize = N::VALUE + 1;
+}
+
+/// Type-level set of pitch classes, with `B0` for C up to `B11` for B
+#[allow(clippy::type_complexity)]
+#[derive(Debug, Default)]
+pub struct Classes<B0, B1, B2, B3, B4, B5, B6, B7, B8, B9, B10, B11>(
+    PhantomData<(B0, B1, B2, B3, B4, B5, B6, B7, B8, B9, B10, B11)>,
+);
+
+pub type NoClasses =
+    Classes<False, False, False, False, False, False, False, False, False, False, False, False>;
+
+pub trait ClassSet {
+    type B0: Bool;
+    type B1: Bool;
+    type B2: Bool;
+    type B3: Bool;
+    type B4: Bool;
+    type B5: Bool;
+    type B6: Bool;
+    type B7: Bool;
+    type B8: Bool;
+    type B9: Bool;
+    type B10: Bool;
+    type B11: Bool;
+    type Union<S: ClassSet>: ClassSet;
+    type Intersection<S: ClassSet>: ClassSet;
+    /// Classes in this set but not in `S`
+    type Without<S: ClassSet>: ClassSet;
+    type Count: Number;
+}
+
+macro_rules! per_class {
+    ($s:ident, $op:ident) => {
+        Classes<
+            <B0 as Bool>::$op<$s::B0>,
+            <B1 as Bool>::$op<$s::B1>,
+            <B2 as Bool>::$op<$s::B2>,
+            <B3 as Bool>::$op<$s::B3>,
+            <B4 as Bool>::$op<$s::B4>,
+            <B5 as Bool>::$op<$s::B5>,
+            <B6 as Bool>::$op<$s::B6>,
+            <B7 as Bool>::$op<$s::B7>,
+            <B8 as Bool>::$op<$s::B8>,
+            <B9 as Bool>::$op<$s::B9>,
+            <B10 as Bool>::$op<$s::B10>,
+            <B11 as Bool>::$op<$s::B11>,
+        >
+    };
+}
+
+impl<B0, B1, B2, B3, B4, B5, B6, B7, B8, B9, B10, B11> ClassSet
+    for Classes<B0, B1, B2, B3, B4, B5, B6, B7, B8, B9, B10, B11>
+where
+    B0: Bool,
+    B1: Bool,
+    B2: Bool,
+    B3: Bool,
+    B4: Bool,
+    B5: Bool,
+    B6: Bool,
+    B7: Bool,
+    B8: Bool,
+    B9: Bool,
+    B10: Bool,
+    B11: Bool,
+{
+    type B0 = B0;
+    type B1 = B1;
+    type B2 = B2;
+    type B3 = B3;
+    type B4 = B4;
+    type B5 = B5;
+    type B6 = B6;
+    type B7 = B7;
+    type B8 = B8;
+    type B9 = B9;
+    type B10 = B10;
+    type B11 = B11;
+    type Union<S: ClassSet> = per_class!(S, Or);
+    type Intersection<S: ClassSet> = per_class!(S, And);
+    type Without<S: ClassSet> = <Self as ClassSet>::Intersection<Complement<S>>;
+    type Count = B0::Plus<
+        B1::Plus<
+            B2::Plus<
+                B3::Plus<
+                    B4::Plus<
+                        B5::Plus<
+                            B6::Plus<B7::Plus<B8::Plus<B9::Plus<B10::Plus<B11::Plus<Zero>>>>>>,
+                        >,
+                    >,
+                >,
+            >,
+        >,
+    >;
+}
+
+type Complement<S> = Classes<
+    <<S as ClassSet>::B0 as Bool>::Not,
+    <<S as ClassSet>::B1 as Bool>::Not,
+    <<S as ClassSet>::B2 as Bool>::Not,
+    <<S as ClassSet>::B3 as Bool>::Not,
+    <<S as ClassSet>::B4 as Bool>::Not,
+    <<S as ClassSet>::B5 as Bool>::Not,
+    <<S as ClassSet>::B6 as Bool>::Not,
+    <<S as ClassSet>::B7 as Bool>::Not,
+    <<S as ClassSet>::B8 as Bool>::Not,
+    <<S as ClassSet>::B9 as Bool>::Not,
+    <<S as ClassSet>::B10 as Bool>::Not,
+    <<S as ClassSet>::B11 as Bool>::Not,
+>;
+
+/// Pitch class of a natural note, and none for the empty `()` slot
+pub trait NaturalClass {
+    type Set: ClassSet;
+}
+
+macro_rules! impl_natural_class {
+    ($t:ty, $($b:ident),+) => {
+        impl NaturalClass for $t {
+            type Set = Classes<$($b),+>;
+        }
+    };
+}
+
+impl_natural_class!(
+    (),
+    False,
+    False,
+    False,
+    False,
+    False,
+    False,
+    False,
+    False,
+    False,
+    False,
+    False,
+    False
+);
+impl_natural_class!(
+    C, True, False, False, False, False, False, False, False, False, False, False, False
+);
+impl_natural_class!(
+    D, False, False, True, False, False, False, False, False, False, False, False, False
+);
+impl_natural_class!(
+    E, False, False, False, False, True, False, False, False, False, False, False, False
+);
+impl_natural_class!(
+    F, False, False, False, False, False, True, False, False, False, False, False, False
+);
+impl_natural_class!(
+    G, False, False, False, False, False, False, False, True, False, False, False, False
+);
+impl_natural_class!(
+    A, False, False, False, False, False, False, False, False, False, True, False, False
+);
+impl_natural_class!(
+    B, False, False, False, False, False, False, False, False, False, False, False, True
+);
+
+/// Moves every pitch class of a set by the accidental's offset
+pub trait Shift: Accidental {
+    type Of<S: ClassSet>: ClassSet;
+}
+
+impl Shift for Natural {
+    type Of<S: ClassSet> = S;
+}
+
+impl Shift for Sharpened {
+    type Of<S: ClassSet> = Classes<
+        S::B11,
+        S::B0,
+        S::B1,
+        S::B2,
+        S::B3,
+        S::B4,
+        S::B5,
+        S::B6,
+        S::B7,
+        S::B8,
+        S::B9,
+        S::B10,
+    >;
+}
+
+impl Shift for Flattened {
+    type Of<S: ClassSet> = Classes<
+        S::B1,
+        S::B2,
+        S::B3,
+        S::B4,
+        S::B5,
+        S::B6,
+        S::B7,
+        S::B8,
+        S::B9,
+        S::B10,
+        S::B11,
+        S::B0,
+    >;
+}
+
+impl Shift for DoubleFlattened {
+    type Of<S: ClassSet> = <Flattened as Shift>::Of<<Flattened as Shift>::Of<S>>;
+}
+
+/// Pitch class of a note as a one-element `ClassSet`, empty for `()`
+pub trait PitchClass: Note {
+    type Set: ClassSet;
+}
+
+impl<N: Note> PitchClass for N
+where
+    N::Letter: NaturalClass,
+    N::Accidental: Shift,
+{
+    type Set = <N::Accidental as Shift>::Of<<N::Letter as NaturalClass>::Set>;
+}
+
+type ClassOf<N> = <N as PitchClass>::Set;
+
+macro_rules! union_of {
+    ($last:ty) => { $last };
+    ($first:ty, $($rest:ty),+) => {
+        <$first as ClassSet>::Union<union_of!($($rest),+)>
+    };
+}
+
+/// Pitch classes a chord sounds, as a type, matching `Compare::pitches`
+pub trait PitchClasses {
+    type Set: ClassSet;
+}
+
+impl<C: Chord> PitchClasses for C
+where
+    C::R: PitchClass,
+    C::Bass: PitchClass,
+    C::P1: PitchClass,
+    C::m2: PitchClass,
+    C::M2: PitchClass,
+    C::m3: PitchClass,
+    C::M3: PitchClass,
+    C::P4: PitchClass,
+    C::A4: PitchClass,
+    C::d5: PitchClass,
+    C::P5: PitchClass,
+    C::A5: PitchClass,
+    C::M6: PitchClass,
+    C::A6: PitchClass,
+    C::d7: PitchClass,
+    C::m7: PitchClass,
+    C::M7: PitchClass,
+    C::m9: PitchClass,
+    C::M9: PitchClass,
+    C::A9: PitchClass,
+    C::P11: PitchClass,
+    C::A11: PitchClass,
+    C::m13: PitchClass,
+    C::M13: PitchClass,
+{
+    // The bass of an unslashed chord is its root, even with the root omitted
+    type Set = union_of!(
+        <ClassOf<C::Bass> as ClassSet>::Without<ClassOf<C::R>>,
+        ClassOf<C::P1>,
+        ClassOf<C::m2>,
+        ClassOf<C::M2>,
+        ClassOf<C::m3>,
+        ClassOf<C::M3>,
+        ClassOf<C::P4>,
+        ClassOf<C::A4>,
+        ClassOf<C::d5>,
+        ClassOf<C::P5>,
+        ClassOf<C::A5>,
+        ClassOf<C::M6>,
+        ClassOf<C::A6>,
+        ClassOf<C::d7>,
+        ClassOf<C::m7>,
+        ClassOf<C::M7>,
+        ClassOf<C::m9>,
+        ClassOf<C::M9>,
+        ClassOf<C::A9>,
+        ClassOf<C::P11>,
+        ClassOf<C::A11>,
+        ClassOf<C::m13>,
+        ClassOf<C::M13>
+    );
+}
+
+impl<Upper, Lower> PitchClasses for Poly<Upper, Lower>
+where
+    Upper: Chord + PitchClasses,
+    Lower: Chord + PitchClasses,
+{
+    type Set = <Lower::Set as ClassSet>::Union<Upper::Set>;
+}
+
+/// The empty `ClassSet`
+pub trait Empty: ClassSet {}
+impl Empty for NoClasses {}
+
+/// Holds when every tone is also in `C2`, e.g. `MajorSeventh<C>` in `MajorNinth<C>`
+///
+/// ```compile_fail,E0277
+/// # use chord::{chord::*, compare::*, note::*};
+/// fn subset<C1: SubsetOf<C2>, C2>() {}
+/// // B is not in C9
+/// subset::<MajorSeventh<C>, Ninth<C>>();
+/// ```
+pub trait SubsetOf<C2> {}
+
+impl<C1: PitchClasses, C2: PitchClasses> SubsetOf<C2> for C1 where
+    <C1::Set as ClassSet>::Without<C2::Set>: Empty
+{
+}
+
+/// Holds when every tone of `C2` is also in this chord
+///
+/// ```compile_fail,E0277
+/// # use chord::{chord::*, compare::*, note::*};
+/// fn superset<C1: SupersetOf<C2>, C2>() {}
+/// superset::<Seventh<C>, Sixth<C>>();
+/// ```
+pub trait SupersetOf<C2> {}
+
+impl<C1, C2: SubsetOf<C1>> SupersetOf<C2> for C1 {}
+
+/// Holds when both chords sound the same pitch classes, however spelled, e.g.
+/// `Sixth<C>` and `MinorSeventh<A>`
+///
+/// ```compile_fail,E0277
+/// # use chord::{chord::*, compare::*, note::*};
+/// fn enharmonic<C1: EnharmonicTo<C2>, C2>() {}
+/// enharmonic::<Seventh<C>, MajorSeventh<C>>();
+/// ```
+pub trait EnharmonicTo<C2> {}
+
+impl<C1: PitchClasses, C2: PitchClasses<Set = C1::Set>> EnharmonicTo<C2> for C1 {}
+
+/// Number of tones also in `C2`, e.g. `Succ<Succ<Succ<Zero>>>` between
+/// `MajorSeventh<C>` and `MinorSeventh<A>`
+///
+/// ```compile_fail,E0271
+/// # use chord::{chord::*, compare::*, note::*};
+/// fn three<C1: CommonTones<C2, Count = Succ<Succ<Succ<Zero>>>>, C2>() {}
+/// // Only C and E
+/// three::<MajorSeventh<C>, Minor<A>>();
+/// ```
+pub trait CommonTones<C2> {
+    type Count: Number;
+}
+
+impl<C1: PitchClasses, C2: PitchClasses> CommonTones<C2> for C1 {
+    type Count = <<C1::Set as ClassSet>::Intersection<C2::Set> as ClassSet>::Count;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::symbol::parse_chord;
+    use test_case::test_case;
+
+    fn chord(symbol: &str) -> ChordValue {
+        parse_chord(symbol).unwrap()
+    }
+
+    fn names(pitches: &[Pitch]) -> Vec<String> {
+        pitches.iter().map(|p| p.name()).collect()
+    }
+
+    #[test_case("Cmaj7", "Am7",  &["C", "E", "G"])]
+    #[test_case("G7",    "D♭7",  &["B", "F"])]
+    #[test_case("C",     "F♯",   &[])]
+    #[test_case("C/B♭",  "C7",   &["B♭ ", "C", "E", "G"])]
+    fn test_common_tones(a: &str, b: &str, common: &[&str]) {
+        assert_eq!(names(&chord(a).common_tones(&chord(b))), common);
+        assert_eq!(chord(b).common_tone_count(&chord(a)), common.len());
+    }
+
+    #[test_case("C6", "Am7", true)]
+    #[test_case("Cdim7", "E♭dim7", true)]
+    #[test_case("C♯", "D♭", true)]
+    #[test_case("C/E", "C", true)]
+    #[test_case("C7", "Cmaj7", false)]
+    fn test_enharmonic(a: &str, b: &str, enharmonic: bool) {
+        assert_eq!(chord(a).is_enharmonic(&chord(b)), enharmonic);
+        assert_eq!(chord(b).is_enharmonic(&chord(a)), enharmonic);
+    }
+
+    #[test_case("Cmaj7", "Cmaj9", true)]
+    #[test_case("C", "Am7", true)]
+    #[test_case("Cmaj7", "C9", false)]
+    #[test_case("Csus4", "C11", true)]
+    fn test_subset(a: &str, b: &str, subset: bool) {
+        assert_eq!(chord(a).is_subset_of(&chord(b)), subset);
+        assert_eq!(chord(b).is_superset_of(&chord(a)), subset);
+    }
+
+    #[test_case("C",     "Am",    &["G", "A"])]
+    #[test_case("Cmaj7", "Cmaj9", &["D"])]
+    #[test_case("C6",    "Am7",   &[])]
+    fn test_symmetric_difference(a: &str, b: &str, difference: &[&str]) {
+        assert_eq!(names(&chord(a).symmetric_difference(&chord(b))), difference);
+    }
+
+    #[test]
+    fn test_type_level() {
+        let cmaj7 = <MajorSeventh<C>>::default();
+        assert!(cmaj7.is_subset_of(&<MajorNinth<C>>::default()));
+        assert!(<Sixth<C>>::default().is_enharmonic(&<MinorSeventh<A>>::default()));
+        assert!(<Major<Sharp<C>>>::default().is_enharmonic(&<Major<Flat<D>>>::default()));
+        assert_eq!(cmaj7.common_tone_count(&<MinorSeventh<E>>::default()), 3);
+        assert_eq!(
+            names(&<Omit1<Seventh<C>>>::default().pitches()),
+            ["E", "G", "B♭ "]
+        );
+        assert_eq!(
+            names(&<Slash<Major<C>, Flat<B>>>::default().pitches()),
+            ["B♭ ", "C", "E", "G"]
+        );
+        // Chord types and runtime chords compare with each other
+        assert!(cmaj7.is_subset_of(&chord("Cmaj9")));
+        assert!(chord("Am7").is_superset_of(&<Major<C>>::default()));
+    }
+
+    fn subset<C1: SubsetOf<C2>, C2>() {}
+    fn superset<C1: SupersetOf<C2>, C2>() {}
+    fn enharmonic<C1: EnharmonicTo<C2>, C2>() {}
+
+    fn common<C1: CommonTones<C2>, C2>() -> usize {
+        <C1::Count as Number>::VALUE
+    }
+
+    #[test]
+    fn test_type_level_relations() {
+        subset::<MajorSeventh<C>, MajorNinth<C>>();
+        subset::<Sus4<Major<C>>, Eleventh<C>>();
+        subset::<Major<C>, Major<C>>();
+        superset::<MajorNinth<C>, MajorSeventh<C>>();
+        superset::<Poly<Major<D>, Seventh<C>>, Seventh<C>>();
+        enharmonic::<Sixth<C>, MinorSeventh<A>>();
+        enharmonic::<Major<Sharp<C>>, Major<Flat<D>>>();
+        enharmonic::<DiminishedSeventh<C>, DiminishedSeventh<Flat<E>>>();
+        enharmonic::<Slash<Major<C>, E>, Major<C>>();
+        enharmonic::<Omit1<Seventh<C>>, Slash<Omit1<Seventh<C>>, C>>();
+        enharmonic::<Poly<Major<D>, Seventh<C>>, Sharp11<Thirteenth<C>>>();
+        let _: Succ<Succ<Succ<Zero>>> = Default::default();
+        assert_eq!(common::<MajorSeventh<C>, MinorSeventh<A>>(), 3);
+        assert_eq!(common::<Major<C>, Major<Sharp<F>>>(), 0);
+        assert_eq!(common::<Slash<Major<C>, Flat<B>>, Seventh<C>>(), 4);
+        assert_eq!(common::<UstFlatII<C>, Seventh<C>>(), 4);
+    }
+
+    #[test_case(<MajorSeventh<C>>::default(), <MinorSeventh<A>>::default())]
+    #[test_case(<Seventh<G>>::default(), <Seventh<Flat<D>>>::default())]
+    #[test_case(<Slash<Major<C>, Flat<B>>>::default(), <Seventh<C>>::default())]
+    #[test_case(<DiminishedSeventh<C>>::default(), <Major<A>>::default())]
+    fn test_common_tones_agree<C1, C2>(a: C1, b: C2)
+    where
+        C1: Chord + CommonTones<C2>,
+        C2: Chord,
+    {
+        assert_eq!(common::<C1, C2>(), a.common_tone_count(&b));
+    }
+
+    #[test]
+    fn test_poly() {
+        let ust: PolyValue = "D|C7".parse().unwrap();
+        assert_eq!(
+            names(&ust.pitches()),
+            ["C", "E", "G", "B♭ ", "D", "F♯ ", "A"]
+        );
+        assert!(ust.is_enharmonic(&chord("C13♯11")));
+        assert!(chord("C7").is_subset_of(&ust));
+        assert_eq!(names(&ust.common_tones(&chord("D"))), ["D", "F♯ ", "A"]);
+        let flat_ii = <UstFlatII<C>>::default();
+        assert_eq!(flat_ii.common_tone_count(&<Seventh<C>>::default()), 4);
+        assert!(flat_ii.is_enharmonic(&flat_ii.value()));
+    }
+}
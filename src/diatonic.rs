@@ -0,0 +1,793 @@
+use crate::chord::*;
+use crate::formula::*;
+use crate::key::*;
+use crate::note::*;
+
+/// Chords stacked in thirds on degree `D` of a mode, for any root
+pub trait Harmonize<D: Degree>: Mode {
+    type Triad<R: Note>: Chord;
+    type Seventh<R: Note>: Chord;
+    type Ninth<R: Note>: Chord;
+}
+
+/// Mode whose every degree can be harmonized
+pub trait Harmony:
+    Harmonize<I>
+    + Harmonize<II>
+    + Harmonize<III>
+    + Harmonize<IV>
+    + Harmonize<V>
+    + Harmonize<VI>
+    + Harmonize<VII>
+{
+}
+impl<M> Harmony for M where
+    M: Harmonize<I>
+        + Harmonize<II>
+        + Harmonize<III>
+        + Harmonize<IV>
+        + Harmonize<V>
+        + Harmonize<VI>
+        + Harmonize<VII>
+{
+}
+
+macro_rules! impl_harmonize {
+    ($mode:tt; $($d:tt => $triad:tt, $seventh:tt, $ninth:ty;)+) => {
+        $(impl Harmonize<$d> for $mode {
+            type Triad<R: Note> = $triad<R>;
+            type Seventh<R: Note> = $seventh<R>;
+            type Ninth<R: Note> = $ninth;
+        })+
+    };
+}
+
+impl_harmonize!(Ionian;
+    I => Major, MajorSeventh, MajorNinth<R>;
+    II => Minor, MinorSeventh, MinorNinth<R>;
+    III => Minor, MinorSeventh, Flat9<MinorSeventh<R>>;
+    IV => Major, MajorSeventh, MajorNinth<R>;
+    V => Major, Seventh, Ninth<R>;
+    VI => Minor, MinorSeventh, MinorNinth<R>;
+    VII => Dim, HalfDiminished, Flat9<HalfDiminished<R>>;
+);
+impl_harmonize!(Dorian;
+    I => Minor, MinorSeventh, MinorNinth<R>;
+    II => Minor, MinorSeventh, Flat9<MinorSeventh<R>>;
+    III => Major, MajorSeventh, MajorNinth<R>;
+    IV => Major, Seventh, Ninth<R>;
+    V => Minor, MinorSeventh, MinorNinth<R>;
+    VI => Dim, HalfDiminished, Flat9<HalfDiminished<R>>;
+    VII => Major, MajorSeventh, MajorNinth<R>;
+);
+impl_harmonize!(Phrygian;
+    I => Minor, MinorSeventh, Flat9<MinorSeventh<R>>;
+    II => Major, MajorSeventh, MajorNinth<R>;
+    III => Major, Seventh, Ninth<R>;
+    IV => Minor, MinorSeventh, MinorNinth<R>;
+    V => Dim, HalfDiminished, Flat9<HalfDiminished<R>>;
+    VI => Major, MajorSeventh, MajorNinth<R>;
+    VII => Minor, MinorSeventh, MinorNinth<R>;
+);
+impl_harmonize!(Lydian;
+    I => Major, MajorSeventh, MajorNinth<R>;
+    II => Major, Seventh, Ninth<R>;
+    III => Minor, MinorSeventh, MinorNinth<R>;
+    IV => Dim, HalfDiminished, Flat9<HalfDiminished<R>>;
+    V => Major, MajorSeventh, MajorNinth<R>;
+    VI => Minor, MinorSeventh, MinorNinth<R>;
+    VII => Minor, MinorSeventh, Flat9<MinorSeventh<R>>;
+);
+impl_harmonize!(Mixolydian;
+    I => Major, Seventh, Ninth<R>;
+    II => Minor, MinorSeventh, MinorNinth<R>;
+    III => Dim, HalfDiminished, Flat9<HalfDiminished<R>>;
+    IV => Major, MajorSeventh, MajorNinth<R>;
+    V => Minor, MinorSeventh, MinorNinth<R>;
+    VI => Minor, MinorSeventh, Flat9<MinorSeventh<R>>;
+    VII => Major, MajorSeventh, MajorNinth<R>;
+);
+impl_harmonize!(Aeolian;
+    I => Minor, MinorSeventh, MinorNinth<R>;
+    II => Dim, HalfDiminished, Flat9<HalfDiminished<R>>;
+    III => Major, MajorSeventh, MajorNinth<R>;
+    IV => Minor, MinorSeventh, MinorNinth<R>;
+    V => Minor, MinorSeventh, Flat9<MinorSeventh<R>>;
+    VI => Major, MajorSeventh, MajorNinth<R>;
+    VII => Major, Seventh, Ninth<R>;
+);
+impl_harmonize!(Locrian;
+    I => Dim, HalfDiminished, Flat9<HalfDiminished<R>>;
+    II => Major, MajorSeventh, MajorNinth<R>;
+    III => Minor, MinorSeventh, MinorNinth<R>;
+    IV => Minor, MinorSeventh, Flat9<MinorSeventh<R>>;
+    V => Major, MajorSeventh, MajorNinth<R>;
+    VI => Major, Seventh, Ninth<R>;
+    VII => Minor, MinorSeventh, MinorNinth<R>;
+);
+
+type Root<K, D> = <D as DiatonicDegree<K>>::N;
+
+/// Triad on degree `D` of `K`, e.g. `DiatonicTriad<MinorKey<C>, V>` is `Minor<G>`
+pub type DiatonicTriad<K, D> = <<K as Key>::Mode as Harmonize<D>>::Triad<Root<K, D>>;
+/// Seventh chord on degree `D` of `K`
+pub type DiatonicSeventh<K, D> = <<K as Key>::Mode as Harmonize<D>>::Seventh<Root<K, D>>;
+/// Ninth chord on degree `D` of `K`
+pub type DiatonicNinth<K, D> = <<K as Key>::Mode as Harmonize<D>>::Ninth<Root<K, D>>;
+
+/// Dominant seventh a fifth above degree `D` of `K`, e.g. `SecondaryDominant<MajorKey<C>, II>` (V7/ii) is `Seventh<A>`
+pub type SecondaryDominant<K, D> = Seventh<<P5 as IntervalResolve<Root<K, D>>>::R>;
+/// Diminished seventh a semitone below degree `D` of `K`, e.g. `SecondaryLeadingTone<MajorKey<C>, V>` (vii°7/V) is `DiminishedSeventh<Sharp<F>>`
+pub type SecondaryLeadingTone<K, D> = DiminishedSeventh<<M7 as IntervalResolve<Root<K, D>>>::R>;
+
+/// Major triad on the lowered second degree of `K` in first inversion (N6), e.g. `D♭/F` in C
+pub type Neapolitan<K> = Inversion<Major<<m2 as IntervalResolve<<K as Key>::I>>::R>, First>;
+
+/// `K`'s tonic in the mode `M`, e.g. `Parallel<MajorKey<C>, Aeolian>` has the notes of C minor
+pub type Parallel<K, M> = ModalKey<<K as Key>::I, M>;
+/// Triad on degree `D` of the parallel mode `M`, e.g. `Borrowed<MajorKey<C>, Aeolian, VI>` (♭VI) is `Major<Flat<A>>`
+pub type Borrowed<K, M, D> = DiatonicTriad<Parallel<K, M>, D>;
+/// Seventh chord on degree `D` of the parallel mode `M`
+pub type BorrowedSeventh<K, M, D> = DiatonicSeventh<Parallel<K, M>, D>;
+
+/// Chords built from the notes of the key on each degree
+pub trait DiatonicChords: Key<Mode: Harmony> {
+    type Triads: Default;
+    type Sevenths: Default;
+    fn triads(&self) -> Self::Triads {
+        Default::default()
+    }
+    fn sevenths(&self) -> Self::Sevenths {
+        Default::default()
+    }
+    /// Chord of `tones` stacked thirds on the 1-based `degree`: 3 for the
+    /// triad, 4 for the seventh chord and up to 7 for the thirteenth.
+    /// `None` for a degree outside 1..=7 or a tone count outside 3..=7
+    fn chord(&self, degree: usize, tones: usize) -> Option<ChordValue> {
+        stack(&self.pitches(), degree, tones)
+    }
+    /// Chord of `tones` stacked thirds on `degree` of the parallel mode `M`, like `Borrowed`.
+    /// `None` as for `chord`, or when the mode does not have seven notes
+    fn borrowed<M: Mode>(&self, degree: usize, tones: usize) -> Option<ChordValue> {
+        let scale = M::default().scale().pitches(self.pitches()[0]);
+        stack(&scale.try_into().ok()?, degree, tones)
+    }
+    /// Neapolitan sixth, like `Neapolitan`
+    fn neapolitan(&self) -> ChordValue {
+        let tonic = self.pitches()[0];
+        ChordValue::new(tonic.up(1, 1), Quality::Major).over(tonic.up(3, 5))
+    }
+    /// Italian augmented sixth on the lowered sixth degree, like `ItalianSixth`
+    fn italian_sixth(&self) -> ChordValue {
+        ChordValue::new(self.pitches()[0].up(5, 8), Quality::ItalianSixth)
+    }
+    /// French augmented sixth on the lowered sixth degree, like `FrenchSixth`
+    fn french_sixth(&self) -> ChordValue {
+        ChordValue::new(self.pitches()[0].up(5, 8), Quality::FrenchSixth)
+    }
+    /// German augmented sixth on the lowered sixth degree, like `GermanSixth`
+    fn german_sixth(&self) -> ChordValue {
+        ChordValue::new(self.pitches()[0].up(5, 8), Quality::GermanSixth)
+    }
+    /// Dominant seventh resolving to the 1-based `degree`, like `SecondaryDominant`.
+    /// `None` for a degree outside 1..=7
+    fn secondary_dominant(&self, degree: usize) -> Option<ChordValue> {
+        let target = self.pitches().get(degree.checked_sub(1)?).copied()?;
+        Some(ChordValue::new(target.up(4, 7), Quality::Seventh))
+    }
+    /// Diminished seventh resolving to the 1-based `degree`, like `SecondaryLeadingTone`.
+    /// `None` for a degree outside 1..=7
+    fn secondary_leading_tone(&self, degree: usize) -> Option<ChordValue> {
+        let target = self.pitches().get(degree.checked_sub(1)?).copied()?;
+        Some(ChordValue::new(
+            target.up(6, 11),
+            Quality::DiminishedSeventh,
+        ))
+    }
+}
+
+impl<K: Key<Mode: Harmony>> DiatonicChords for K {
+    type Triads = (
+        DiatonicTriad<K, I>,
+        DiatonicTriad<K, II>,
+        DiatonicTriad<K, III>,
+        DiatonicTriad<K, IV>,
+        DiatonicTriad<K, V>,
+        DiatonicTriad<K, VI>,
+        DiatonicTriad<K, VII>,
+    );
+    type Sevenths = (
+        DiatonicSeventh<K, I>,
+        DiatonicSeventh<K, II>,
+        DiatonicSeventh<K, III>,
+        DiatonicSeventh<K, IV>,
+        DiatonicSeventh<K, V>,
+        DiatonicSeventh<K, VI>,
+        DiatonicSeventh<K, VII>,
+    );
+}
+
+/// Note of `K` without alteration, or the empty `()` slot. Spelling counts,
+/// so `Sharp<A>` is not in `MajorKey<Flat<E>>` even though `Flat<B>` is.
+pub trait InScale<K: Key>: Note {}
+impl<K: Key> InScale<K> for () {}
+impl<K: Key, N: Pitched + DegreeIn<K>> InScale<K> for N where N::D: DiatonicDegree<K> {}
+
+/// Holds when every note of the chord, bass included, is diatonic to `K`,
+/// e.g. `Seventh<G>: InKey<MajorKey<C>>`, and for tuples of such chords.
+/// Chromatic chords opt out by being wrapped in `Chromatic`, also inside
+/// other wrappers such as `Sus4<Chromatic<Seventh<A>>>`.
+///
+/// ```compile_fail,E0277
+/// # use chord::{chord::*, diatonic::*, key::*, note::*};
+/// fn in_key<K: Key, C: InKey<K>>() {}
+/// in_key::<MajorKey<C>, Seventh<D>>();
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use chord::{chord::*, diatonic::*, key::*, note::*};
+/// fn in_key<K: Key, C: InKey<K>>() {}
+/// in_key::<MajorKey<C>, Borrowed<MajorKey<C>, Aeolian, VI>>();
+/// ```
+///
+/// Spelling counts, as for `InScale`:
+///
+/// ```compile_fail,E0277
+/// # use chord::{chord::*, diatonic::*, key::*, note::*};
+/// fn in_key<K: Key, C: InKey<K>>() {}
+/// in_key::<MajorKey<Flat<E>>, Seventh<Sharp<A>>>();
+/// ```
+///
+/// A slash bass outside the key, or one chromatic chord in a tuple, is enough:
+///
+/// ```compile_fail,E0277
+/// # use chord::{chord::*, diatonic::*, key::*, note::*};
+/// fn in_key<K: Key, C: InKey<K>>() {}
+/// in_key::<MajorKey<C>, Slash<Major<C>, Flat<B>>>();
+/// ```
+///
+/// ```compile_fail,E0277
+/// # use chord::{chord::*, diatonic::*, key::*, note::*};
+/// fn in_key<K: Key, C: InKey<K>>() {}
+/// in_key::<MajorKey<C>, (Minor<D>, Seventh<A>)>();
+/// ```
+pub trait InKey<K: Key> {}
+
+impl<K: Key, C: Chord + Marking> InKey<K> for C where C::Mark: KeyCheck<K, C> {}
+
+/// Holds when every note of the chord is diatonic to `K`, whether marked or not
+pub trait NotesInKey<K: Key> {}
+
+impl<K: Key, C: Chord> NotesInKey<K> for C
+where
+    C::Bass: InScale<K>,
+    C::P1: InScale<K>,
+    C::m2: InScale<K>,
+    C::M2: InScale<K>,
+    C::m3: InScale<K>,
+    C::M3: InScale<K>,
+    C::P4: InScale<K>,
+    C::A4: InScale<K>,
+    C::d5: InScale<K>,
+    C::P5: InScale<K>,
+    C::A5: InScale<K>,
+    C::M6: InScale<K>,
+    C::A6: InScale<K>,
+    C::d7: InScale<K>,
+    C::m7: InScale<K>,
+    C::M7: InScale<K>,
+    C::m9: InScale<K>,
+    C::M9: InScale<K>,
+    C::A9: InScale<K>,
+    C::P11: InScale<K>,
+    C::A11: InScale<K>,
+    C::m13: InScale<K>,
+    C::M13: InScale<K>,
+{
+}
+
+/// Whether a chord is marked `Chromatic`, itself or inside wrappers such as
+/// `Sus4` and `Slash`. Chords of other crates implement it to pass `InKey`
+pub trait Marking {
+    /// `Marked`, `Unmarked` or `FromParallel`
+    type Mark;
+}
+
+/// Mark of a `Chromatic` chord, in every key
+#[derive(Debug, Default)]
+pub struct Marked;
+/// Mark of a chord in a key only when its notes are
+#[derive(Debug, Default)]
+pub struct Unmarked;
+/// Mark of a `BorrowedFrom` chord, in a key when its notes are in the parallel mode `M`
+#[derive(Debug, Default)]
+pub struct FromParallel<M: Mode>(M);
+
+/// Holds when a chord `C` with this mark is in `K`
+pub trait KeyCheck<K: Key, C> {}
+impl<K: Key, C: NotesInKey<K>> KeyCheck<K, C> for Unmarked {}
+impl<K: Key, C> KeyCheck<K, C> for Marked {}
+impl<K: Key, C, M: Mode> KeyCheck<K, C> for FromParallel<M>
+where
+    Parallel<K, M>: Key,
+    C: NotesInKey<Parallel<K, M>>,
+{
+}
+
+macro_rules! impl_unmarked {
+    ($($t:ident),+) => {
+        $(impl<R: Note> Marking for $t<R> {
+            type Mark = Unmarked;
+        })+
+    };
+}
+
+impl_unmarked!(
+    Major,
+    Minor,
+    Aug,
+    Dim,
+    Seventh,
+    MajorSeventh,
+    MinorSeventh,
+    HalfDiminished,
+    DiminishedSeventh,
+    MinorMajorSeventh,
+    AugmentedSeventh,
+    AugmentedMajorSeventh,
+    Sixth,
+    MinorSixth,
+    SixNine,
+    Ninth,
+    MajorNinth,
+    MinorNinth,
+    Eleventh,
+    MinorEleventh,
+    Thirteenth,
+    MajorThirteenth,
+    MinorThirteenth,
+    SeventhFlatNinth,
+    SeventhSharpNinth,
+    SeventhSharpEleventh,
+    SeventhFlatThirteenth,
+    Altered,
+    Power,
+    Quartal,
+    SoWhat,
+    Quintal,
+    Cluster
+);
+
+impl<K: Key> Marking for ItalianSixth<K> {
+    type Mark = Unmarked;
+}
+impl<K: Key> Marking for FrenchSixth<K> {
+    type Mark = Unmarked;
+}
+impl<K: Key> Marking for GermanSixth<K> {
+    type Mark = Unmarked;
+}
+
+impl<R: Note, T: Formulas<R>> Marking for Formula<R, T> {
+    type Mark = Unmarked;
+}
+
+// Wrappers keep the mark of the chord they wrap
+macro_rules! impl_marking_for_wrapper {
+    ($($t:ident),+) => {
+        $(impl<C: Chord + Marking> Marking for $t<C> {
+            type Mark = C::Mark;
+        })+
+    };
+}
+
+impl_marking_for_wrapper!(
+    Sus2, Sus4, Omit1, Omit3, Omit5, Add2, Add4, Add6, Add9, Add11, Flat5, Sharp5, Flat9, Sharp9,
+    Sharp11, Flat13
+);
+
+impl<C: Chord + Marking, N: Note> Marking for Slash<C, N> {
+    type Mark = C::Mark;
+}
+
+impl<C: Chord + Marking, N: InversionOf<C>> Marking for Inversion<C, N> {
+    type Mark = C::Mark;
+}
+
+impl<C: Chord> Marking for Chromatic<C> {
+    type Mark = Marked;
+}
+
+/// Borrowed or secondary chord `C` placed deliberately outside the key, so
+/// it passes `InKey` for any key. Otherwise it is the chord itself.
+#[derive(Debug, Default)]
+pub struct Chromatic<C: Chord>(C);
+
+impl<C: Chord> Chord for Chromatic<C> {
+    type R = C::R;
+    type Bass = C::Bass;
+    type P1 = C::P1;
+    type m2 = C::m2;
+    type M2 = C::M2;
+    type m3 = C::m3;
+    type M3 = C::M3;
+    type P4 = C::P4;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = C::P5;
+    type A5 = C::A5;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = C::M9;
+    type A9 = C::A9;
+    type P11 = C::P11;
+    type A11 = C::A11;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        self.0.name()
+    }
+    fn value(&self) -> ChordValue {
+        self.0.value()
+    }
+}
+
+impl<C, I> TransposeBy<I> for Chromatic<C>
+where
+    C: Chord + TransposeBy<I>,
+    C::Output: Chord,
+{
+    type Output = Chromatic<C::Output>;
+}
+
+/// Chord `C` borrowed from the parallel mode `M`, such as the minor v7 of a
+/// major key from its parallel Aeolian. It passes `InKey<K>` when its notes are
+/// in `Parallel<K, M>`, so it is still checked, and is diatonic in keys of mode `M`.
+///
+/// ```compile_fail,E0277
+/// # use chord::{chord::*, diatonic::*, key::*, note::*};
+/// fn in_key<K: Key, C: InKey<K>>() {}
+/// // B is not in C minor
+/// in_key::<MajorKey<C>, BorrowedFrom<Aeolian, Seventh<G>>>();
+/// ```
+#[derive(Debug, Default)]
+pub struct BorrowedFrom<M: Mode, C: Chord>(M, C);
+
+impl<M: Mode, C: Chord> Chord for BorrowedFrom<M, C> {
+    type R = C::R;
+    type Bass = C::Bass;
+    type P1 = C::P1;
+    type m2 = C::m2;
+    type M2 = C::M2;
+    type m3 = C::m3;
+    type M3 = C::M3;
+    type P4 = C::P4;
+    type A4 = C::A4;
+    type d5 = C::d5;
+    type P5 = C::P5;
+    type A5 = C::A5;
+    type M6 = C::M6;
+    type A6 = C::A6;
+    type d7 = C::d7;
+    type m7 = C::m7;
+    type M7 = C::M7;
+    type m9 = C::m9;
+    type M9 = C::M9;
+    type A9 = C::A9;
+    type P11 = C::P11;
+    type A11 = C::A11;
+    type m13 = C::m13;
+    type M13 = C::M13;
+    fn name(&self) -> String {
+        self.1.name()
+    }
+    fn value(&self) -> ChordValue {
+        self.1.value()
+    }
+}
+
+impl<M: Mode, C: Chord> Marking for BorrowedFrom<M, C> {
+    type Mark = FromParallel<M>;
+}
+
+impl<M, C, I> TransposeBy<I> for BorrowedFrom<M, C>
+where
+    M: Mode,
+    C: Chord + TransposeBy<I>,
+    C::Output: Chord,
+{
+    type Output = BorrowedFrom<M, C::Output>;
+}
+
+macro_rules! impl_in_key_for_tuple {
+    () => {};
+    ($first:ident $(, $rest:ident)*) => {
+        impl<K: Key, $first: InKey<K>, $($rest: InKey<K>),*> InKey<K> for ($first, $($rest,)*) {}
+        impl_in_key_for_tuple!($($rest),*);
+    };
+}
+
+impl_in_key_for_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+
+/// Name the chord of every other note of `scale` from `degree`. As usual
+/// for major and dominant thirteenths, the eleventh is left out of them, and
+/// a dominant eleventh keeps its third as `add11`. `None` unless `degree` is
+/// in 1..=7, `tones` in 3..=7 and the triad on `degree` is tertian
+fn stack(scale: &[Pitch; 7], degree: usize, tones: usize) -> Option<ChordValue> {
+    if !(1..=7).contains(&degree) || !(3..=7).contains(&tones) {
+        return None;
+    }
+    let root = scale[degree - 1];
+    // Semitones above the root of the third, fifth, seventh, ninth, eleventh and thirteenth
+    let above: Vec<usize> = (1..tones)
+        .map(|i| (scale[(degree - 1 + 2 * i) % 7].id() + 12 - root.id()) % 12)
+        .collect();
+    let mut quality = match (above[0], above[1]) {
+        (4, 7) => Quality::Major,
+        (3, 7) => Quality::Minor,
+        (3, 6) => Quality::Dim,
+        (4, 8) => Quality::Aug,
+        _ => return None,
+    };
+    let mut modifiers = vec![];
+    if let Some(seventh) = above.get(2) {
+        quality = match (quality, seventh) {
+            (Quality::Major, 11) => Quality::MajorSeventh,
+            (Quality::Major, _) => Quality::Seventh,
+            (Quality::Minor, 11) => Quality::MinorMajorSeventh,
+            (Quality::Minor, _) => Quality::MinorSeventh,
+            (Quality::Dim, 9) => Quality::DiminishedSeventh,
+            (Quality::Dim, _) => Quality::HalfDiminished,
+            (_, 11) => Quality::AugmentedMajorSeventh,
+            _ => Quality::AugmentedSeventh,
+        };
+    }
+    if let Some(ninth) = above.get(3) {
+        match (quality, ninth) {
+            (Quality::Seventh, 2) => quality = Quality::Ninth,
+            (Quality::MajorSeventh, 2) => quality = Quality::MajorNinth,
+            (Quality::MinorSeventh, 2) => quality = Quality::MinorNinth,
+            (_, 1) => modifiers.push(Modifier::Flat9),
+            (_, 3) => modifiers.push(Modifier::Sharp9),
+            _ => modifiers.push(Modifier::Add9),
+        }
+    }
+    if let Some(eleventh) = above.get(4) {
+        match (quality, eleventh) {
+            (Quality::MinorNinth, 5) => quality = Quality::MinorEleventh,
+            (_, 6) => modifiers.push(Modifier::Sharp11),
+            _ => modifiers.push(Modifier::Add11),
+        }
+    }
+    if let Some(thirteenth) = above.get(5) {
+        match (quality, thirteenth) {
+            (Quality::MinorEleventh, 9) => quality = Quality::MinorThirteenth,
+            (Quality::Ninth, 9) => {
+                quality = Quality::Thirteenth;
+                modifiers.retain(|m| *m != Modifier::Add11);
+            }
+            (Quality::MajorNinth, 9) => {
+                quality = Quality::MajorThirteenth;
+                modifiers.retain(|m| *m != Modifier::Add11);
+            }
+            (_, 8) => modifiers.push(Modifier::Flat13),
+            _ => modifiers.push(Modifier::Add6),
+        }
+    }
+    Some(
+        modifiers
+            .into_iter()
+            .fold(ChordValue::new(root, quality), ChordValue::with),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    fn names<K: DiatonicChords>(key: &K, tones: usize) -> Vec<String> {
+        (1..=7)
+            .map(|d| key.chord(d, tones).unwrap().name())
+            .collect()
+    }
+
+    #[test]
+    fn test_triads() {
+        let (i, ii, iii, iv, v, vi, vii) = <MajorKey<C>>::default().triads();
+        assert_eq!(
+            [
+                i.name(),
+                ii.name(),
+                iii.name(),
+                iv.name(),
+                v.name(),
+                vi.name(),
+                vii.name()
+            ],
+            ["C", "Dm", "Em", "F", "G", "Am", "Bdim"]
+        );
+        let (i, ii, iii, iv, v, vi, vii) = <MinorKey<C>>::default().triads();
+        assert_eq!(
+            [
+                i.name(),
+                ii.name(),
+                iii.name(),
+                iv.name(),
+                v.name(),
+                vi.name(),
+                vii.name()
+            ],
+            ["Cm", "Ddim", "E♭ ", "Fm", "Gm", "A♭ ", "B♭ "]
+        );
+    }
+
+    #[test]
+    fn test_sevenths() {
+        let (i, ii, iii, iv, v, vi, vii) = <MajorKey<Flat<E>>>::default().sevenths();
+        assert_eq!(
+            [
+                i.name(),
+                ii.name(),
+                iii.name(),
+                iv.name(),
+                v.name(),
+                vi.name(),
+                vii.name()
+            ],
+            ["E♭ maj7", "Fm7", "Gm7", "A♭ maj7", "B♭ 7", "Cm7", "Dm7♭5"]
+        );
+        let _: MajorSeventh<A> = <DiatonicSeventh<MinorKey<Sharp<C>>, VI>>::default();
+        let _: Flat9<MinorSeventh<E>> = <DiatonicNinth<MajorKey<C>, III>>::default();
+        assert_eq!(
+            <DiatonicNinth<MinorKey<A>, VII>>::default().notes(),
+            <Ninth<G>>::default().notes()
+        );
+    }
+
+    #[test]
+    fn test_secondary() {
+        let _: Seventh<A> = <SecondaryDominant<MajorKey<C>, II>>::default();
+        let _: Seventh<D> = <SecondaryDominant<MajorKey<C>, V>>::default();
+        let _: Seventh<Flat<E>> = <SecondaryDominant<MinorKey<C>, VI>>::default();
+        let _: DiminishedSeventh<Sharp<F>> = <SecondaryLeadingTone<MajorKey<C>, V>>::default();
+        let _: DiminishedSeventh<B> = <SecondaryLeadingTone<MajorKey<Flat<B>>, II>>::default();
+
+        let key = <MajorKey<Flat<B>>>::default();
+        assert_eq!(
+            key.secondary_dominant(2),
+            Some(<SecondaryDominant<MajorKey<Flat<B>>, II>>::default().value())
+        );
+        assert_eq!(key.secondary_dominant(6).unwrap().name(), "D7");
+        assert_eq!(key.secondary_leading_tone(5).unwrap().name(), "Edim7");
+        assert_eq!(key.secondary_dominant(7).unwrap().name(), "E7");
+        assert_eq!(key.secondary_leading_tone(1).unwrap().name(), "Adim7");
+        assert_eq!(key.secondary_dominant(0), None);
+        assert_eq!(key.secondary_dominant(8), None);
+        assert_eq!(key.secondary_leading_tone(0), None);
+        assert_eq!(key.secondary_leading_tone(8), None);
+        assert_eq!(
+            <SecondaryLeadingTone<MajorKey<C>, II>>::default().name(),
+            "C♯ dim7"
+        );
+    }
+
+    #[test]
+    fn test_chromatic_predominants() {
+        let _: Inversion<Major<Flat<D>>, First> = <Neapolitan<MajorKey<C>>>::default();
+        let _: Inversion<Major<Flat<B>>, First> = <Neapolitan<MinorKey<A>>>::default();
+        let key = <MinorKey<A>>::default();
+        assert_eq!(
+            key.neapolitan(),
+            <Neapolitan<MinorKey<A>>>::default().value()
+        );
+        assert_eq!(key.neapolitan().name(), "B♭ /D");
+        assert_eq!(
+            key.italian_sixth(),
+            <ItalianSixth<MinorKey<A>>>::default().value()
+        );
+        assert_eq!(
+            key.french_sixth(),
+            <FrenchSixth<MinorKey<A>>>::default().value()
+        );
+        assert_eq!(
+            key.german_sixth(),
+            <GermanSixth<MinorKey<A>>>::default().value()
+        );
+        assert_eq!(key.french_sixth().notes(), "F   A   B   D♯ ");
+    }
+
+    #[test]
+    fn test_borrowed() {
+        let _: Major<Flat<A>> = <Borrowed<MajorKey<C>, Aeolian, VI>>::default();
+        let _: Major<Flat<B>> = <Borrowed<MajorKey<C>, Aeolian, VII>>::default();
+        let _: Minor<F> = <Borrowed<MajorKey<C>, Aeolian, IV>>::default();
+        let _: Major<Flat<D>> = <Borrowed<MajorKey<C>, Phrygian, II>>::default();
+        let _: Major<D> = <Borrowed<MinorKey<A>, Dorian, IV>>::default();
+        let _: MajorSeventh<F> = <BorrowedSeventh<MajorKey<G>, Mixolydian, VII>>::default();
+
+        let key = <MajorKey<E>>::default();
+        assert_eq!(key.borrowed::<Aeolian>(6, 3).unwrap().name(), "C");
+        assert_eq!(key.borrowed::<Aeolian>(4, 4).unwrap().name(), "Am7");
+        assert_eq!(
+            key.borrowed::<Phrygian>(2, 3).unwrap(),
+            <Borrowed<MajorKey<E>, Phrygian, II>>::default().value()
+        );
+        assert_eq!(
+            crate::roman::roman_numeral(&key.borrowed::<Mixolydian>(7, 3).unwrap(), &key),
+            "♭VII"
+        );
+    }
+
+    #[test_case(3, ["C", "Dm", "Em", "F", "G", "Am", "Bdim"])]
+    #[test_case(4, ["Cmaj7", "Dm7", "Em7", "Fmaj7", "G7", "Am7", "Bm7♭5"])]
+    #[test_case(5, ["Cmaj9", "Dm9", "Em7♭9", "Fmaj9", "G9", "Am9", "Bm7♭5♭9"])]
+    fn test_stacked_major(tones: usize, expected: [&str; 7]) {
+        assert_eq!(names(&<MajorKey<C>>::default(), tones), expected);
+    }
+
+    #[test]
+    fn test_stacked_extensions() {
+        let key = <MajorKey<C>>::default();
+        assert_eq!(key.chord(5, 7).unwrap().name(), "G13");
+        assert_eq!(key.chord(5, 6).unwrap().name(), "G9add11");
+        assert_eq!(key.chord(2, 6).unwrap().name(), "Dm11");
+        assert_eq!(key.chord(1, 7).unwrap().name(), "Cmaj13");
+        assert_eq!(key.chord(4, 7).unwrap().name(), "Fmaj13♯11");
+        assert_eq!(key.chord(3, 6).unwrap().name(), "Em7♭9add11");
+        assert_eq!(key.chord(6, 7).unwrap().name(), "Am11♭13");
+        assert_eq!(key.chord(5, 4), Some(key.sevenths().4.value()));
+        assert_eq!(<MinorKey<A>>::default().chord(1, 4).unwrap().name(), "Am7");
+    }
+
+    #[test_case(0, 3)]
+    #[test_case(8, 3)]
+    #[test_case(1, 2)]
+    #[test_case(1, 8)]
+    fn test_stacked_out_of_range(degree: usize, tones: usize) {
+        assert_eq!(<MajorKey<C>>::default().chord(degree, tones), None);
+        assert_eq!(
+            <MajorKey<C>>::default().borrowed::<Aeolian>(degree, tones),
+            None
+        );
+    }
+
+    fn in_key<K: Key, C: InKey<K>>() {}
+
+    #[test]
+    fn test_in_key() {
+        in_key::<MajorKey<C>, Seventh<G>>();
+        in_key::<MajorKey<C>, HalfDiminished<B>>();
+        in_key::<MajorKey<C>, Slash<MajorSeventh<F>, G>>();
+        in_key::<MinorKey<E>, DiatonicNinth<MinorKey<E>, IV>>();
+        in_key::<MinorKey<C>, Borrowed<MajorKey<C>, Aeolian, VI>>();
+        in_key::<MajorKey<C>, (Minor<D>, Seventh<G>, MajorSeventh<C>)>();
+        in_key::<MajorKey<Flat<E>>, Seventh<Flat<B>>>();
+        // Chromatic chords are rejected, see the doc tests of `InKey`, unless marked as such
+        in_key::<MajorKey<C>, Chromatic<SecondaryDominant<MajorKey<C>, II>>>();
+        in_key::<MajorKey<C>, (Minor<D>, Chromatic<Seventh<A>>)>();
+        // also inside other wrappers
+        in_key::<MajorKey<C>, Sus4<Chromatic<Seventh<A>>>>();
+        in_key::<MajorKey<C>, Slash<Chromatic<Seventh<A>>, E>>();
+        in_key::<MajorKey<C>, Inversion<Flat9<Chromatic<Seventh<A>>>, First>>();
+        // Borrowed chords are checked against the parallel mode
+        in_key::<MajorKey<C>, BorrowedFrom<Aeolian, MinorSeventh<G>>>();
+        in_key::<MinorKey<C>, BorrowedFrom<Aeolian, MinorSeventh<G>>>();
+        in_key::<MajorKey<C>, Add9<BorrowedFrom<Aeolian, Major<Flat<A>>>>>();
+        // Unmarked wrappers are checked by the notes they leave, here A, D, E and G
+        in_key::<MajorKey<C>, Sus4<Seventh<A>>>();
+    }
+
+    #[test]
+    fn test_chromatic() {
+        let chord = <Chromatic<Seventh<A>>>::default();
+        assert_eq!(chord.name(), "A7");
+        assert_eq!(chord.value(), <Seventh<A>>::default().value());
+        assert_eq!(chord.notes(), <Seventh<A>>::default().notes());
+        let _: Chromatic<Seventh<B>> = <Transpose<Chromatic<Seventh<A>>, M2>>::default();
+    }
+}
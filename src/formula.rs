@@ -0,0 +1,368 @@
+use crate::chord::*;
+use crate::key::*;
+use crate::note::*;
+
+/// Chord on `R` with the tones of the intervals in `T`, a tuple such as
+/// `(M3, P5, M7)`, so new qualities need no `Chord` impl of their own:
+/// `type Lydian7<R> = Formula<R, (M3, P5, A11, m7)>`.
+///
+/// The root is always included. Formulas matching a chord symbol, a quality
+/// with alterations, a suspension, added tones and omissions, are named by it,
+/// such as "Cmaj7" or "C7♯11". Others list their functions, as in "C(♭2,3)",
+/// have no `symbol`, and their `value` is the `nearest_symbol`, with the fewest
+/// tones missing or extra.
+#[derive(Debug, Default)]
+pub struct Formula<R: Note, T: Formulas<R>>(R, T);
+
+/// Slots filled by one interval of a `Formula` on `R`
+#[allow(non_camel_case_types)]
+pub trait FormulaTone<R: Note>: Default {
+    type P1: Note;
+    type m2: Note;
+    type M2: Note;
+    type m3: Note;
+    type M3: Note;
+    type P4: Note;
+    type A4: Note;
+    type d5: Note;
+    type P5: Note;
+    type A5: Note;
+    type M6: Note;
+    type A6: Note;
+    type d7: Note;
+    type m7: Note;
+    type M7: Note;
+    type m9: Note;
+    type M9: Note;
+    type A9: Note;
+    type P11: Note;
+    type A11: Note;
+    type m13: Note;
+    type M13: Note;
+}
+
+/// Slots filled by a tuple of intervals, each by the first interval for it
+#[allow(non_camel_case_types)]
+pub trait Formulas<R: Note>: Default {
+    type P1: Note;
+    type m2: Note;
+    type M2: Note;
+    type m3: Note;
+    type M3: Note;
+    type P4: Note;
+    type A4: Note;
+    type d5: Note;
+    type P5: Note;
+    type A5: Note;
+    type M6: Note;
+    type A6: Note;
+    type d7: Note;
+    type m7: Note;
+    type M7: Note;
+    type m9: Note;
+    type M9: Note;
+    type A9: Note;
+    type P11: Note;
+    type A11: Note;
+    type m13: Note;
+    type M13: Note;
+}
+
+/// `$note` if `$slot` is `$this`, otherwise the empty slot
+macro_rules! slot_note {
+    (P1, P1, $note:ty) => {
+        $note
+    };
+    (m2, m2, $note:ty) => {
+        $note
+    };
+    (M2, M2, $note:ty) => {
+        $note
+    };
+    (m3, m3, $note:ty) => {
+        $note
+    };
+    (M3, M3, $note:ty) => {
+        $note
+    };
+    (P4, P4, $note:ty) => {
+        $note
+    };
+    (A4, A4, $note:ty) => {
+        $note
+    };
+    (d5, d5, $note:ty) => {
+        $note
+    };
+    (P5, P5, $note:ty) => {
+        $note
+    };
+    (A5, A5, $note:ty) => {
+        $note
+    };
+    (M6, M6, $note:ty) => {
+        $note
+    };
+    (A6, A6, $note:ty) => {
+        $note
+    };
+    (d7, d7, $note:ty) => {
+        $note
+    };
+    (m7, m7, $note:ty) => {
+        $note
+    };
+    (M7, M7, $note:ty) => {
+        $note
+    };
+    (m9, m9, $note:ty) => {
+        $note
+    };
+    (M9, M9, $note:ty) => {
+        $note
+    };
+    (A9, A9, $note:ty) => {
+        $note
+    };
+    (P11, P11, $note:ty) => {
+        $note
+    };
+    (A11, A11, $note:ty) => {
+        $note
+    };
+    (m13, m13, $note:ty) => {
+        $note
+    };
+    (M13, M13, $note:ty) => {
+        $note
+    };
+    ($slot:ident, $this:ident, $note:ty) => {
+        ()
+    };
+}
+
+macro_rules! impl_formula_tone {
+    ($($interval:ident => $slot:ident),+) => {
+        $(impl_formula_tone!(
+            @slots $interval, $slot;
+            P1 m2 M2 m3 M3 P4 A4 d5 P5 A5 M6 A6 d7 m7 M7 m9 M9 A9 P11 A11 m13 M13
+        );)+
+    };
+    (@slots $interval:ident, $slot:ident; $($this:ident)+) => {
+        impl<R: Note> FormulaTone<R> for $interval {
+            $(type $this = slot_note!($slot, $this, <$interval as IntervalResolve<R>>::R);)+
+        }
+    };
+}
+
+// Augmented fourths and minor sixths are spelled as the ♯11 and ♭13 they stand for
+impl_formula_tone!(
+    P1 => P1, m2 => m2, M2 => M2, m3 => m3, M3 => M3, P4 => P4, A4 => A11, d5 => d5,
+    P5 => P5, A5 => A5, m6 => m13, M6 => M6, A6 => A6, d7 => d7, m7 => m7, M7 => M7,
+    m9 => m9, M9 => M9, A9 => A9, P11 => P11, A11 => A11, m13 => m13, M13 => M13
+);
+
+impl<R: Note> Formulas<R> for () {
+    type P1 = ();
+    type m2 = ();
+    type M2 = ();
+    type m3 = ();
+    type M3 = ();
+    type P4 = ();
+    type A4 = ();
+    type d5 = ();
+    type P5 = ();
+    type A5 = ();
+    type M6 = ();
+    type A6 = ();
+    type d7 = ();
+    type m7 = ();
+    type M7 = ();
+    type m9 = ();
+    type M9 = ();
+    type A9 = ();
+    type P11 = ();
+    type A11 = ();
+    type m13 = ();
+    type M13 = ();
+}
+
+macro_rules! impl_formulas {
+    () => {};
+    ($first:ident $(, $rest:ident)*) => {
+        impl_formulas!(
+            @slots ($first $(, $rest)*), $first, ($($rest,)*);
+            P1 m2 M2 m3 M3 P4 A4 d5 P5 A5 M6 A6 d7 m7 M7 m9 M9 A9 P11 A11 m13 M13
+        );
+        impl_formulas!($($rest),*);
+    };
+    (@slots ($($t:ident),+), $first:ident, $rest:ty; $($slot:ident)+) => {
+        impl<R: Note, $($t: FormulaTone<R>),+> Formulas<R> for ($($t,)+)
+        where
+            $rest: Formulas<R>,
+            $($first::$slot: OrElse<<$rest as Formulas<R>>::$slot>,)+
+        {
+            $(type $slot = <$first::$slot as OrElse<<$rest as Formulas<R>>::$slot>>::R;)+
+        }
+    };
+}
+
+impl_formulas!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+
+/// Modifiers tried on each quality, in the order of a canonical name
+const MODIFIERS: [Modifier; 15] = [
+    Modifier::Flat5,
+    Modifier::Sharp5,
+    Modifier::Flat9,
+    Modifier::Sharp9,
+    Modifier::Sharp11,
+    Modifier::Flat13,
+    Modifier::Sus4,
+    Modifier::Sus2,
+    Modifier::Add2,
+    Modifier::Add4,
+    Modifier::Add6,
+    Modifier::Add9,
+    Modifier::Add11,
+    Modifier::Omit3,
+    Modifier::Omit5,
+];
+
+/// `quality` on `root` with each modifier that takes it closer to `slots`
+fn spell(slots: &Slots, quality: Quality, root: Pitch) -> ChordValue {
+    let mut chord = ChordValue::new(root, quality);
+    for modifier in MODIFIERS {
+        let changed = chord.clone().with(modifier);
+        if changed.validate().is_ok()
+            && distance(&changed.slots(), slots) < distance(&chord.slots(), slots)
+        {
+            chord = changed;
+        }
+    }
+    chord
+}
+
+/// Slots filled in one chord but not the other, or spelled differently
+fn distance(a: &Slots, b: &Slots) -> usize {
+    a.iter().zip(b).filter(|(a, b)| a != b).count()
+}
+
+impl<R: Note, T: Formulas<R>> Formula<R, T> {
+    /// Chord symbol with exactly these tones, spelled the same way
+    pub fn symbol(&self) -> Option<ChordValue> {
+        Some(self.nearest_symbol()).filter(|chord| chord.slots() == self.slots())
+    }
+
+    /// Chord symbol closest to these tones, then with the fewest modifiers.
+    /// Its tones differ from the formula's when `symbol` is `None`
+    pub fn nearest_symbol(&self) -> ChordValue {
+        let slots = self.slots();
+        let root = root_pitch::<R>();
+        let rank = |chord: &ChordValue| (distance(&chord.slots(), &slots), chord.modifiers.len());
+        let mut best = spell(&slots, Quality::Major, root);
+        for quality in Quality::ALL.into_iter().filter(|q| !q.is_augmented_sixth()) {
+            let chord = spell(&slots, quality, root);
+            if rank(&chord) < rank(&best) {
+                best = chord;
+            }
+        }
+        best
+    }
+}
+
+impl<R: Note, T: Formulas<R>> Chord for Formula<R, T> {
+    type R = R;
+    type Bass = Self::R;
+    type P1 = <P1 as IntervalResolve<R>>::R;
+    type m2 = T::m2;
+    type M2 = T::M2;
+    type m3 = T::m3;
+    type M3 = T::M3;
+    type P4 = T::P4;
+    type A4 = T::A4;
+    type d5 = T::d5;
+    type P5 = T::P5;
+    type A5 = T::A5;
+    type M6 = T::M6;
+    type A6 = T::A6;
+    type d7 = T::d7;
+    type m7 = T::m7;
+    type M7 = T::M7;
+    type m9 = T::m9;
+    type M9 = T::M9;
+    type A9 = T::A9;
+    type P11 = T::P11;
+    type A11 = T::A11;
+    type m13 = T::m13;
+    type M13 = T::M13;
+    fn name(&self) -> String {
+        if let Some(chord) = self.symbol() {
+            return chord.name();
+        }
+        let functions: Vec<&str> = self.tones().iter().skip(1).map(|t| t.function()).collect();
+        format!("{}({})", self.0.name(), functions.join(","))
+    }
+    fn value(&self) -> ChordValue {
+        self.nearest_symbol()
+    }
+}
+
+impl<R: Note, T: Formulas<R>, I: IntervalResolve<R>> TransposeBy<I> for Formula<R, T>
+where
+    T: Formulas<I::R>,
+{
+    type Output = Formula<I::R, T>;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    type Lydian7<R> = Formula<R, (M3, P5, A11, m7)>;
+
+    #[test_case(<Formula<C, (M3, P5, M7)>>::default(),      <MajorSeventh<C>>::default())]
+    #[test_case(<Formula<D, (m3, P5, m7, M9)>>::default(),  <MinorNinth<D>>::default())]
+    #[test_case(<Formula<Flat<B>, (M3, P5)>>::default(),    <Major<Flat<B>>>::default())]
+    #[test_case(<Formula<E, (P5, m3)>>::default(),          <Minor<E>>::default())]
+    #[test_case(<Formula<G, (M3, P5, m7, m9)>>::default(),  <SeventhFlatNinth<G>>::default())]
+    #[test_case(<Formula<C, (P5,)>>::default(),             <Power<C>>::default())]
+    #[test_case(<Formula<B, (m3, d5, m7)>>::default(),      <HalfDiminished<B>>::default())]
+    fn test_formula(formula: impl Chord, chord: impl Chord) {
+        assert_eq!(formula.name(), chord.name());
+        assert_eq!(formula.slots(), chord.slots());
+        assert_eq!(formula.notes(), chord.notes());
+        assert_eq!(formula.value(), chord.value());
+    }
+
+    #[test_case(<Formula<C, (M3, A4)>>::default(),          "C(♯11)omit5")]
+    #[test_case(<Formula<C, (P4, P5, m7)>>::default(),      "C7sus4")]
+    #[test_case(<Formula<F, (M3, P5, M7, A11)>>::default(), "Fmaj7♯11")]
+    #[test_case(<Formula<A, (m3, M9)>>::default(),          "Amadd9omit5")]
+    #[test_case(<Formula<G, (M3, P5, m7, A9, m13)>>::default(), "G7♯9♭13")]
+    fn test_modified(formula: impl Chord, name: &str) {
+        assert_eq!(formula.name(), name);
+        let chord = crate::symbol::parse_chord(name).unwrap();
+        assert_eq!(formula.value(), chord);
+        assert_eq!(formula.slots(), chord.slots());
+    }
+
+    #[test]
+    fn test_custom_quality() {
+        let lydian7 = <Lydian7<C>>::default();
+        assert_eq!(lydian7.name(), "C7♯11");
+        assert_eq!(lydian7.notes(), "C   E   G   B♭  F♯ ");
+        assert_eq!(lydian7.value(), <Sharp11<Seventh<C>>>::default().value());
+        assert_eq!(lydian7.symbol(), Some(lydian7.value()));
+        // No symbol has a ♭2, so the value leaves it out
+        let formula = <Formula<C, (m2, M3, P5)>>::default();
+        assert_eq!(formula.name(), "C(♭2,3,5)");
+        assert_eq!(formula.symbol(), None);
+        assert_ne!(formula.value().slots(), formula.slots());
+        assert_eq!(formula.value(), <Major<C>>::default().value());
+        assert_eq!(<Formula<C, (M3, m6)>>::default().notes(), "C   E   A♭ ");
+        assert_eq!(<Formula<C, ()>>::default().notes(), "C  ");
+        let _: Lydian7<D> = <Transpose<Lydian7<C>, M2>>::default();
+        let _: () = <<Lydian7<C> as Chord>::M7>::default();
+    }
+}
@@ -1,35 +1,60 @@
 use crate::note::*;
-use std::fmt::Debug;
+use crate::scale::Scale;
+use std::fmt::{Debug, Display};
 
-pub trait Interval {}
+pub trait Interval {
+    /// Letter names spanned, within one octave
+    fn steps(&self) -> usize;
+    /// Semitones spanned, within one octave
+    fn semitones(&self) -> usize;
+}
 pub trait IntervalResolve<N> {
     type R: Note;
 }
 
 macro_rules! impl_interval {
-    ($t:tt, $($b:tt)+) => {
+    ($t:tt, $steps:expr, $semitones:expr, $($b:tt)+) => {
         #[allow(non_camel_case_types)]
         #[derive(Debug, Default)]
         pub struct $t;
-        impl Interval for $t {}
+        impl Interval for $t {
+            fn steps(&self) -> usize {
+                $steps
+            }
+            fn semitones(&self) -> usize {
+                $semitones
+            }
+        }
         impl<N: Note> IntervalResolve<N> for $t {
              type R = $($b)+;
         }
     };
 }
 
-impl_interval!(P1, N::R);
-impl_interval!(m2, <<P1 as IntervalResolve<N>>::R as Note>::ST);
-impl_interval!(M2, <<P1 as IntervalResolve<N>>::R as Note>::T);
-impl_interval!(m3, <<M2 as IntervalResolve<N>>::R as Note>::ST);
-impl_interval!(M3, <<M2 as IntervalResolve<N>>::R as Note>::T);
-impl_interval!(P4, <<M3 as IntervalResolve<N>>::R as Note>::ST);
-impl_interval!(d5, <<P4 as IntervalResolve<N>>::R as Note>::ST);
-impl_interval!(P5, <<P4 as IntervalResolve<N>>::R as Note>::T);
-impl_interval!(A5, <<P5 as IntervalResolve<N>>::R as Note>::S);
-impl_interval!(M6, <<P5 as IntervalResolve<N>>::R as Note>::T);
-impl_interval!(m7, <<M6 as IntervalResolve<N>>::R as Note>::ST);
-impl_interval!(M7, <<M6 as IntervalResolve<N>>::R as Note>::T);
+impl_interval!(P1, 0, 0, N::R);
+impl_interval!(m2, 1, 1, <<P1 as IntervalResolve<N>>::R as Note>::ST);
+impl_interval!(M2, 1, 2, <<P1 as IntervalResolve<N>>::R as Note>::T);
+impl_interval!(m3, 2, 3, <<M2 as IntervalResolve<N>>::R as Note>::ST);
+impl_interval!(M3, 2, 4, <<M2 as IntervalResolve<N>>::R as Note>::T);
+impl_interval!(P4, 3, 5, <<M3 as IntervalResolve<N>>::R as Note>::ST);
+impl_interval!(A4, 3, 6, <<P4 as IntervalResolve<N>>::R as Note>::S);
+impl_interval!(d5, 4, 6, <<P4 as IntervalResolve<N>>::R as Note>::ST);
+impl_interval!(P5, 4, 7, <<P4 as IntervalResolve<N>>::R as Note>::T);
+impl_interval!(A5, 4, 8, <<P5 as IntervalResolve<N>>::R as Note>::S);
+impl_interval!(m6, 5, 8, <<P5 as IntervalResolve<N>>::R as Note>::ST);
+impl_interval!(M6, 5, 9, <<P5 as IntervalResolve<N>>::R as Note>::T);
+impl_interval!(A6, 5, 10, <<M6 as IntervalResolve<N>>::R as Note>::S);
+// The minor seventh flattened on its own letter, so B𝄫 rather than A above C
+impl_interval!(d7, 6, 9, <<m7 as IntervalResolve<N>>::R as Note>::LF);
+impl_interval!(m7, 6, 10, <<M6 as IntervalResolve<N>>::R as Note>::ST);
+impl_interval!(M7, 6, 11, <<M6 as IntervalResolve<N>>::R as Note>::T);
+impl_interval!(m9, 1, 1, <m2 as IntervalResolve<N>>::R);
+impl_interval!(M9, 1, 2, <M2 as IntervalResolve<N>>::R);
+impl_interval!(A9, 1, 3, <<M9 as IntervalResolve<N>>::R as Note>::S);
+impl_interval!(P11, 3, 5, <P4 as IntervalResolve<N>>::R);
+impl_interval!(A11, 3, 6, <<P11 as IntervalResolve<N>>::R as Note>::S);
+impl_interval!(m13, 5, 8, <<P5 as IntervalResolve<N>>::R as Note>::ST);
+impl_interval!(M13, 5, 9, <M6 as IntervalResolve<N>>::R);
 
 #[allow(dead_code)]
 pub type KeyTuple<K> = (
@@ -43,6 +68,7 @@ pub type KeyTuple<K> = (
 );
 
 pub trait Key: Default {
+    type Mode: Mode;
     type I: Note;
     type II: Note;
     type III: Note;
@@ -74,17 +100,219 @@ pub trait Key: Default {
     fn key_tuple(&self) -> KeyTuple<Self> {
         Default::default()
     }
+    fn pitches(&self) -> [Pitch; 7] {
+        [
+            self.i().pitch(),
+            self.ii().pitch(),
+            self.iii().pitch(),
+            self.iv().pitch(),
+            self.v().pitch(),
+            self.vi().pitch(),
+            self.vii().pitch(),
+        ]
+        .map(|p| p.expect("scale degrees are notes"))
+    }
+    fn name(&self) -> String {
+        self.name_in(NamingStyle::English)
+    }
+    fn name_in(&self, style: NamingStyle) -> String {
+        Self::Mode::default().key_name(&self.i().name_in(style), style)
+    }
+    /// Degree of `note` in this key, or `None` for the empty note `()`
+    fn degree_of<N: Note>(&self, note: &N) -> Option<ScaleDegree> {
+        note.pitch().map(|pitch| self.degree_of_pitch(pitch))
+    }
+    fn degree_of_pitch(&self, pitch: Pitch) -> ScaleDegree {
+        let scale = self.pitches();
+        let degree = (pitch.letter + 7 - scale[0].letter) % 7;
+        let alteration = ((pitch.id() + 18 - scale[degree].id()) % 12) as i8 - 6;
+        ScaleDegree {
+            degree: degree + 1,
+            alteration,
+            semitones: (pitch.id() + 12 - scale[0].id()) % 12,
+        }
+    }
 }
 
+pub trait Mode: Debug + Default {
+    /// Name of the key on `tonic`, already spelled in `style`
+    fn key_name(&self, tonic: &str, style: NamingStyle) -> String;
+    fn scale(&self) -> Scale;
+}
+
+#[derive(Debug, Default)]
+pub struct Ionian;
+#[derive(Debug, Default)]
+pub struct Aeolian;
+
+impl Mode for Ionian {
+    fn key_name(&self, tonic: &str, style: NamingStyle) -> String {
+        match style {
+            NamingStyle::English => format!("{tonic} major"),
+            NamingStyle::German => format!("{tonic}-Dur"),
+            NamingStyle::Japanese => format!("{tonic}長調"),
+        }
+    }
+    fn scale(&self) -> Scale {
+        Scale::Ionian
+    }
+}
+impl Mode for Aeolian {
+    fn key_name(&self, tonic: &str, style: NamingStyle) -> String {
+        match style {
+            NamingStyle::English => format!("{} minor", tonic.to_lowercase()),
+            NamingStyle::German => format!("{}-Moll", tonic.to_lowercase()),
+            NamingStyle::Japanese => format!("{tonic}短調"),
+        }
+    }
+    fn scale(&self) -> Scale {
+        Scale::Aeolian
+    }
+}
+
+macro_rules! impl_church_mode {
+    ($t:tt, $german:expr, $japanese:expr) => {
+        #[derive(Debug, Default)]
+        pub struct $t;
+        impl Mode for $t {
+            fn key_name(&self, tonic: &str, style: NamingStyle) -> String {
+                match style {
+                    NamingStyle::English => format!("{tonic} {}", stringify!($t)),
+                    NamingStyle::German => format!("{tonic}-{}", $german),
+                    NamingStyle::Japanese => format!("{tonic}{}", $japanese),
+                }
+            }
+            fn scale(&self) -> Scale {
+                Scale::$t
+            }
+        }
+    };
+}
+
+impl_church_mode!(Dorian, "Dorisch", "ドリア旋法");
+impl_church_mode!(Phrygian, "Phrygisch", "フリギア旋法");
+impl_church_mode!(Lydian, "Lydisch", "リディア旋法");
+impl_church_mode!(Mixolydian, "Mixolydisch", "ミクソリディア旋法");
+impl_church_mode!(Locrian, "Lokrisch", "ロクリア旋法");
+
+/// Interval above the tonic of each degree of a mode
+pub trait ModeDegrees: Mode {
+    type II;
+    type III;
+    type IV;
+    type V;
+    type VI;
+    type VII;
+}
+
+macro_rules! impl_mode_degrees {
+    ($($mode:tt => $ii:tt, $iii:tt, $iv:tt, $v:tt, $vi:tt, $vii:tt;)+) => {
+        $(impl ModeDegrees for $mode {
+            type II = $ii;
+            type III = $iii;
+            type IV = $iv;
+            type V = $v;
+            type VI = $vi;
+            type VII = $vii;
+        })+
+    };
+}
+
+impl_mode_degrees!(
+    Ionian => M2, M3, P4, P5, M6, M7;
+    Dorian => M2, m3, P4, P5, M6, m7;
+    Phrygian => m2, m3, P4, P5, m6, m7;
+    Lydian => M2, M3, A4, P5, M6, M7;
+    Mixolydian => M2, M3, P4, P5, M6, m7;
+    Aeolian => M2, m3, P4, P5, m6, m7;
+    Locrian => m2, m3, P4, d5, m6, m7;
+);
+
 #[derive(Debug, Default)]
 pub struct MajorKey<Tonic: Note>(Tonic);
 
 #[derive(Debug, Default)]
 pub struct MinorKey<Tonic: Note>(Tonic);
 
+/// Key in any mode, e.g. `ModalKey<D, Dorian>`, spelled from the tonic by the mode's intervals
+#[derive(Debug, Default)]
+pub struct ModalKey<Tonic: Note, M: Mode>(Tonic, M);
+
+impl<Tonic: Note, M: ModeDegrees> Key for ModalKey<Tonic, M>
+where
+    M::II: IntervalResolve<Tonic>,
+    M::III: IntervalResolve<Tonic>,
+    M::IV: IntervalResolve<Tonic>,
+    M::V: IntervalResolve<Tonic>,
+    M::VI: IntervalResolve<Tonic>,
+    M::VII: IntervalResolve<Tonic>,
+{
+    type Mode = M;
+    type I = Tonic::R;
+    type II = <M::II as IntervalResolve<Tonic>>::R;
+    type III = <M::III as IntervalResolve<Tonic>>::R;
+    type IV = <M::IV as IntervalResolve<Tonic>>::R;
+    type V = <M::V as IntervalResolve<Tonic>>::R;
+    type VI = <M::VI as IntervalResolve<Tonic>>::R;
+    type VII = <M::VII as IntervalResolve<Tonic>>::R;
+}
+
+impl<Tonic: Note> Display for MajorKey<Tonic>
+where
+    Self: Key,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+impl<Tonic: Note> Display for MinorKey<Tonic>
+where
+    Self: Key,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+impl<Tonic: Note, M: Mode> Display for ModalKey<Tonic, M>
+where
+    Self: Key,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Move a key, chord or progression up by the interval `I`
+pub trait TransposeBy<I> {
+    type Output;
+}
+
+/// `X` moved up by `I`, e.g. `Transpose<MajorKey<C>, M3>` is `MajorKey<E>`
+pub type Transpose<X, I> = <X as TransposeBy<I>>::Output;
+
+impl<Tonic: Note, I: IntervalResolve<Tonic>> TransposeBy<I> for MajorKey<Tonic>
+where
+    MajorKey<I::R>: Key,
+{
+    type Output = MajorKey<I::R>;
+}
+impl<Tonic: Note, I: IntervalResolve<Tonic>> TransposeBy<I> for MinorKey<Tonic>
+where
+    MinorKey<I::R>: Key,
+{
+    type Output = MinorKey<I::R>;
+}
+impl<Tonic: Note, M: Mode, I: IntervalResolve<Tonic>> TransposeBy<I> for ModalKey<Tonic, M>
+where
+    ModalKey<I::R, M>: Key,
+{
+    type Output = ModalKey<I::R, M>;
+}
+
 macro_rules! impl_key {
     ($t:tt) => {
         impl Key for MajorKey<$t> {
+            type Mode = Ionian;
             type I = <$t as Note>::R;
             type II = <Self::I as Note>::T;
             type III = <Self::II as Note>::T;
@@ -94,6 +322,7 @@ macro_rules! impl_key {
             type VII = <Self::VI as Note>::T;
         }
         impl Key for MinorKey<$t> {
+            type Mode = Aeolian;
             type I = <$t as Note>::R;
             type II = <Self::I as Note>::T;
             type III = <Self::II as Note>::ST;
@@ -119,6 +348,7 @@ macro_rules! impl_key_for_signature {
     };
     ($key:tt, $tonic:tt, $sig:tt) => {
         impl Key for $key<$sig<$tonic>> {
+            type Mode = <$key<$tonic> as Key>::Mode;
             impl_key_for_signature!(inner I, $key, $tonic, $sig);
             impl_key_for_signature!(inner II, $key, $tonic, $sig);
             impl_key_for_signature!(inner III, $key, $tonic, $sig);
@@ -147,6 +377,198 @@ impl_key_for_signature!(MinorKey, A, Flat);
 impl_key_for_signature!(MinorKey, B, Flat);
 impl_key_for_signature!(MinorKey, E, Flat);
 
+/// Diatonic scale degree, optionally altered by `Raised` / `Lowered`
+pub trait Degree: Debug + Default {
+    /// 1-based position in the scale
+    fn degree(&self) -> usize;
+    /// Semitones relative to the diatonic note of the key
+    fn alteration(&self) -> i8;
+    fn name(&self) -> String {
+        ScaleDegree::name_of(self.degree(), self.alteration())
+    }
+}
+
+/// Select the diatonic note of `K` at this degree
+pub trait DiatonicDegree<K: Key>: Degree {
+    type N: Note;
+}
+
+macro_rules! impl_degree {
+    ($t:tt, $d:expr) => {
+        #[derive(Debug, Default)]
+        pub struct $t;
+        impl Degree for $t {
+            fn degree(&self) -> usize {
+                $d
+            }
+            fn alteration(&self) -> i8 {
+                0
+            }
+        }
+        impl<K: Key> DiatonicDegree<K> for $t {
+            type N = K::$t;
+        }
+    };
+}
+
+impl_degree!(I, 1);
+impl_degree!(II, 2);
+impl_degree!(III, 3);
+impl_degree!(IV, 4);
+impl_degree!(V, 5);
+impl_degree!(VI, 6);
+impl_degree!(VII, 7);
+
+#[derive(Debug, Default)]
+pub struct Raised<D: Degree>(D);
+#[derive(Debug, Default)]
+pub struct Lowered<D: Degree>(D);
+
+impl<D: Degree> Degree for Raised<D> {
+    fn degree(&self) -> usize {
+        self.0.degree()
+    }
+    fn alteration(&self) -> i8 {
+        self.0.alteration() + 1
+    }
+}
+impl<D: Degree> Degree for Lowered<D> {
+    fn degree(&self) -> usize {
+        self.0.degree()
+    }
+    fn alteration(&self) -> i8 {
+        self.0.alteration() - 1
+    }
+}
+
+/// Letter-name distance from `From`, as an unaltered degree
+pub trait LetterDistance<From: Note> {
+    type D: Degree;
+}
+
+macro_rules! impl_letter_distance {
+    ($from:ty; $($to:ty),+) => {
+        impl_letter_distance!(inner $from; (I, II, III, IV, V, VI, VII); $($to),+);
+    };
+    (inner $from:ty; ($($d:ty),+); $($to:ty),+) => {
+        $(impl LetterDistance<$from> for $to {
+            type D = $d;
+        })+
+    };
+}
+
+impl_letter_distance!(C; C, D, E, F, G, A, B);
+impl_letter_distance!(D; D, E, F, G, A, B, C);
+impl_letter_distance!(E; E, F, G, A, B, C, D);
+impl_letter_distance!(F; F, G, A, B, C, D, E);
+impl_letter_distance!(G; G, A, B, C, D, E, F);
+impl_letter_distance!(A; A, B, C, D, E, F, G);
+impl_letter_distance!(B; B, C, D, E, F, G, A);
+
+/// Alter degree `D` by the difference between `Self` and `Expected`
+pub trait Alteration<Expected: Accidental, D: Degree>: Accidental {
+    type D: Degree;
+}
+
+macro_rules! impl_alteration {
+    ($actual:ty, $expected:ty, $d:ident => $($r:tt)+) => {
+        impl<$d: Degree> Alteration<$expected, $d> for $actual {
+            type D = $($r)+;
+        }
+    };
+}
+
+impl_alteration!(Natural, Natural, D => D);
+impl_alteration!(Sharpened, Sharpened, D => D);
+impl_alteration!(Flattened, Flattened, D => D);
+impl_alteration!(Sharpened, Natural, D => Raised<D>);
+impl_alteration!(Natural, Flattened, D => Raised<D>);
+impl_alteration!(Sharpened, Flattened, D => Raised<Raised<D>>);
+impl_alteration!(Flattened, Natural, D => Lowered<D>);
+impl_alteration!(Natural, Sharpened, D => Lowered<D>);
+impl_alteration!(Flattened, Sharpened, D => Lowered<Lowered<D>>);
+
+pub trait DegreeIn<K: Key>: Note {
+    type D: Degree;
+}
+
+impl<K, N> DegreeIn<K> for N
+where
+    K: Key,
+    N: Note,
+    N::Letter: LetterDistance<<K::I as Note>::Letter>,
+    <N::Letter as LetterDistance<<K::I as Note>::Letter>>::D: DiatonicDegree<K>,
+    N::Accidental: Alteration<
+        ExpectedAccidental<K, <N::Letter as LetterDistance<<K::I as Note>::Letter>>::D>,
+        <N::Letter as LetterDistance<<K::I as Note>::Letter>>::D,
+    >,
+{
+    type D = <N::Accidental as Alteration<
+        ExpectedAccidental<K, <N::Letter as LetterDistance<<K::I as Note>::Letter>>::D>,
+        <N::Letter as LetterDistance<<K::I as Note>::Letter>>::D,
+    >>::D;
+}
+
+type ExpectedAccidental<K, D> = <<D as DiatonicDegree<K>>::N as Note>::Accidental;
+
+/// Scale degree of `N` in `K`, e.g. `DegreeOf<MajorKey<C>, Sharp<F>>` is `Raised<IV>`
+pub type DegreeOf<K, N> = <N as DegreeIn<K>>::D;
+
+/// Runtime scale degree of a note within a key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScaleDegree {
+    /// 1-based position in the scale
+    pub degree: usize,
+    /// Semitones relative to the diatonic note of the key
+    pub alteration: i8,
+    /// Semitones above the tonic
+    pub semitones: usize,
+}
+
+impl ScaleDegree {
+    fn name_of(degree: usize, alteration: i8) -> String {
+        let numeral = ["I", "II", "III", "IV", "V", "VI", "VII"][degree - 1];
+        let accidental = match alteration {
+            a if a > 0 => "♯".repeat(a as usize),
+            a => "♭".repeat(a.unsigned_abs() as usize),
+        };
+        format!("{accidental}{numeral}")
+    }
+
+    pub fn name(&self) -> String {
+        Self::name_of(self.degree, self.alteration)
+    }
+
+    /// Movable-do solfège syllable, with do on the tonic
+    pub fn solfege(&self) -> &'static str {
+        const MAJOR: [usize; 7] = [0, 2, 4, 5, 7, 9, 11];
+        let from_major = (self.semitones + 12 - MAJOR[self.degree - 1]) % 12;
+        match (self.degree, from_major) {
+            (1, 0) => "do",
+            (1, 1) => "di",
+            (2, 11) => "ra",
+            (2, 0) => "re",
+            (2, 1) => "ri",
+            (3, 11) => "me",
+            (3, 0) => "mi",
+            (4, 11) => "mi",
+            (4, 0) => "fa",
+            (4, 1) => "fi",
+            (5, 11) => "se",
+            (5, 0) => "sol",
+            (5, 1) => "si",
+            (6, 11) => "le",
+            (6, 0) => "la",
+            (6, 1) => "li",
+            (7, 11) => "te",
+            (7, 0) => "ti",
+            _ => [
+                "do", "di", "re", "ri", "mi", "fa", "fi", "sol", "si", "la", "li", "ti",
+            ][self.semitones],
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -214,4 +636,82 @@ mod test {
             expected,
         );
     }
+
+    #[test_case(<MajorKey<C>>::default(), <Sharp<F>>::new(), "♯IV", "fi")]
+    #[test_case(<MajorKey<C>>::default(), <Flat<B>>::new(), "♭VII", "te")]
+    #[test_case(<MajorKey<C>>::default(), G, "V", "sol")]
+    #[test_case(<MajorKey<D>>::default(), <Sharp<F>>::new(), "III", "mi")]
+    #[test_case(<MajorKey<D>>::default(), F, "♭III", "me")]
+    #[test_case(<MinorKey<C>>::default(), <Flat<E>>::new(), "III", "me")]
+    #[test_case(<MinorKey<C>>::default(), B, "♯VII", "ti")]
+    #[test_case(<MajorKey<Sharp<C>>>::default(), E, "♭III", "me")]
+    #[test_case(<MajorKey<Flat<E>>>::default(), <Sharp<A>>::new(), "♯♯IV", "sol")]
+    fn test_degree_of(key: impl Key, note: impl Note, name: &str, solfege: &str) {
+        let degree = key.degree_of(&note).unwrap();
+        assert_eq!(degree.name(), name);
+        assert_eq!(degree.solfege(), solfege);
+    }
+
+    #[test]
+    fn test_degree_of_unpitched() {
+        assert_eq!(<MajorKey<C>>::default().degree_of(&()), None);
+    }
+
+    #[test]
+    fn test_degree_of_type() {
+        assert_eq!(<DegreeOf<MajorKey<C>, Sharp<F>>>::default().name(), "♯IV");
+        assert_eq!(<DegreeOf<MajorKey<C>, Flat<B>>>::default().name(), "♭VII");
+        assert_eq!(<DegreeOf<MajorKey<D>, Sharp<F>>>::default().name(), "III");
+        assert_eq!(<DegreeOf<MinorKey<C>, B>>::default().name(), "♯VII");
+        assert_eq!(<DegreeOf<MajorKey<Sharp<C>>, E>>::default().name(), "♭III");
+        let _: Raised<IV> = <DegreeOf<MajorKey<C>, Sharp<F>>>::default();
+    }
+
+    #[test_case(<MajorKey<Sharp<C>>>::default(), NamingStyle::English, "C♯ major")]
+    #[test_case(<MinorKey<Sharp<C>>>::default(), NamingStyle::English, "c♯ minor")]
+    #[test_case(<MajorKey<Sharp<C>>>::default(), NamingStyle::German, "Cis-Dur")]
+    #[test_case(<MinorKey<Flat<E>>>::default(), NamingStyle::German, "es-Moll")]
+    #[test_case(<MajorKey<B>>::default(), NamingStyle::German, "H-Dur")]
+    #[test_case(<MajorKey<Sharp<C>>>::default(), NamingStyle::Japanese, "嬰ハ長調")]
+    #[test_case(<MinorKey<D>>::default(), NamingStyle::Japanese, "ニ短調")]
+    fn test_key_name(key: impl Key, style: NamingStyle, expected: &str) {
+        assert_eq!(key.name_in(style), expected);
+    }
+
+    #[test]
+    fn test_key_display() {
+        assert_eq!(<MajorKey<Flat<B>>>::default().to_string(), "B♭ major");
+        assert_eq!(<MinorKey<Sharp<F>>>::default().to_string(), "f♯ minor");
+    }
+
+    #[test]
+    fn test_modal_key() {
+        let names = |pitches: [Pitch; 7]| pitches.map(|p| p.name_in(NamingStyle::English));
+        assert_eq!(
+            names(<ModalKey<D, Dorian>>::default().pitches()),
+            ["D", "E", "F", "G", "A", "B", "C"]
+        );
+        assert_eq!(
+            names(<ModalKey<F, Lydian>>::default().pitches()),
+            ["F", "G", "A", "B", "C", "D", "E"]
+        );
+        assert_eq!(
+            names(<ModalKey<Flat<B>, Locrian>>::default().pitches()),
+            ["B♭", "C♭", "D♭", "E♭", "F♭", "G♭", "A♭"]
+        );
+        assert_eq!(
+            <ModalKey<C, Aeolian>>::default().pitches(),
+            <MinorKey<C>>::default().pitches()
+        );
+        assert_eq!(<ModalKey<E, Phrygian>>::default().to_string(), "E Phrygian");
+        let _: ModalKey<A, Mixolydian> = <Transpose<ModalKey<G, Mixolydian>, M2>>::default();
+    }
+
+    #[test]
+    fn test_transpose_key() {
+        let _: MajorKey<E> = <Transpose<MajorKey<C>, M3>>::default();
+        let _: MajorKey<Flat<E>> = <Transpose<MajorKey<D>, m2>>::default();
+        let _: MinorKey<Sharp<F>> = <Transpose<MinorKey<B>, P5>>::default();
+        assert_eq!(<Transpose<MinorKey<A>, m6>>::default().name(), "f minor");
+    }
 }
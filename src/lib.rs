@@ -1,4 +1,12 @@
-mod chord;
-mod key;
-mod note;
-mod progression;
+pub mod chord;
+pub mod compare;
+pub mod diatonic;
+pub mod formula;
+pub mod key;
+pub mod note;
+pub mod poly;
+pub mod progression;
+pub mod recognize;
+pub mod roman;
+pub mod scale;
+pub mod symbol;
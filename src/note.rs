@@ -2,8 +2,13 @@ use std::fmt::Debug;
 
 pub trait Note: Debug + Default {
     type R: Note;
+    /// Natural note sharing the letter name
+    type Letter: Note;
+    type Accidental: Accidental;
     /// Flat
     type F: Note;
+    /// Flat on the same letter, down to a double flat
+    type LF: Note;
     /// Sharp
     type S: Note;
     /// Semitone
@@ -12,6 +17,15 @@ pub trait Note: Debug + Default {
     type T: Note;
     fn name(&self) -> String;
     fn id(&self) -> usize;
+    fn name_in(&self, style: NamingStyle) -> String {
+        self.pitch().map(|p| p.name_in(style)).unwrap_or_default()
+    }
+    fn pitch(&self) -> Option<Pitch> {
+        Some(Pitch::new(
+            letter_index(Self::Letter::default().id()),
+            Self::Accidental::default().offset(),
+        ))
+    }
     fn new() -> Self::R {
         Default::default()
     }
@@ -25,7 +39,10 @@ pub trait Note: Debug + Default {
 
 impl Note for () {
     type R = Self;
+    type Letter = Self;
+    type Accidental = Natural;
     type F = Self;
+    type LF = Self;
     type S = Self;
     type ST = Self;
     type T = Self;
@@ -33,7 +50,167 @@ impl Note for () {
         "".into()
     }
     fn id(&self) -> usize {
-        std::usize::MAX
+        usize::MAX
+    }
+    fn pitch(&self) -> Option<Pitch> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NamingStyle {
+    /// C♯, B♭
+    English,
+    /// Cis, B
+    German,
+    /// 嬰ハ, 変ロ
+    Japanese,
+}
+
+const NATURALS: [usize; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+/// Position of a natural note in C D E F G A B
+pub(crate) fn letter_index(id: usize) -> usize {
+    NATURALS
+        .iter()
+        .position(|&n| n == id)
+        .expect("letter must be a natural note")
+}
+
+/// Runtime note, spelled the same way as the type-level notes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pitch {
+    /// Position of the letter in C D E F G A B
+    pub letter: usize,
+    /// Semitones relative to the natural note, within -2..=1
+    pub accidental: i8,
+}
+
+impl Pitch {
+    /// A double flat keeps its letter, as `Flat<Flat<N>>` of a natural note does;
+    /// other double accidentals are respelled on the neighbouring letter
+    pub fn new(letter: usize, accidental: i8) -> Self {
+        Self::respelled(letter, accidental, -2)
+    }
+
+    fn respelled(letter: usize, accidental: i8, lowest: i8) -> Self {
+        let (mut letter, mut accidental) = (letter % 7, accidental);
+        while accidental > 1 || accidental < lowest {
+            let id = (NATURALS[letter] as i8 + accidental).rem_euclid(12);
+            letter = if accidental > 0 {
+                (letter + 1) % 7
+            } else {
+                (letter + 6) % 7
+            };
+            accidental = (id - NATURALS[letter] as i8 + 18).rem_euclid(12) - 6;
+        }
+        Self { letter, accidental }
+    }
+
+    /// Spell a pitch class, preferring F♯ and otherwise flats for black keys
+    pub fn from_id(id: usize) -> Self {
+        let (letter, accidental) = [
+            (0, 0),
+            (1, -1),
+            (1, 0),
+            (2, -1),
+            (2, 0),
+            (3, 0),
+            (3, 1),
+            (4, 0),
+            (5, -1),
+            (5, 0),
+            (6, -1),
+            (6, 0),
+        ][id % 12];
+        Self::new(letter, accidental)
+    }
+
+    pub fn id(&self) -> usize {
+        (NATURALS[self.letter] as i8 + self.accidental).rem_euclid(12) as usize
+    }
+
+    /// Same format as `Note::name`
+    pub fn name(&self) -> String {
+        let letter = ["C", "D", "E", "F", "G", "A", "B"][self.letter];
+        match self.accidental {
+            1 => format!("{letter}♯ "),
+            -1 => format!("{letter}♭ "),
+            -2 => format!("{letter}𝄫 "),
+            _ => letter.into(),
+        }
+    }
+
+    pub fn name_in(&self, style: NamingStyle) -> String {
+        let letter = self.letter;
+        match style {
+            NamingStyle::English => self.name().trim_end().into(),
+            NamingStyle::German => match self.accidental {
+                1 => format!("{}is", ["C", "D", "E", "F", "G", "A", "H"][letter]),
+                -1 => ["Ces", "Des", "Es", "Fes", "Ges", "As", "B"][letter].into(),
+                -2 => ["Ceses", "Deses", "Eses", "Feses", "Geses", "Asas", "Heses"][letter].into(),
+                _ => ["C", "D", "E", "F", "G", "A", "H"][letter].into(),
+            },
+            NamingStyle::Japanese => format!(
+                "{}{}",
+                match self.accidental {
+                    1 => "嬰",
+                    -1 => "変",
+                    -2 => "重変",
+                    _ => "",
+                },
+                ["ハ", "ニ", "ホ", "ヘ", "ト", "イ", "ロ"][letter]
+            ),
+        }
+    }
+
+    /// Note `steps` letters and `semitones` semitones above, with at most one accidental
+    /// like the type-level intervals
+    pub fn up(&self, steps: usize, semitones: usize) -> Self {
+        let letter = (self.letter + steps) % 7;
+        let id = (self.id() + semitones) % 12;
+        let accidental = (id as i8 - NATURALS[letter] as i8 + 18).rem_euclid(12) - 6;
+        Self::respelled(letter, accidental, -1)
+    }
+
+    /// Semitone lower on the same letter, down to a double flat, as `Flat<N>`
+    pub fn flat(&self) -> Self {
+        Self::new(self.letter, self.accidental - 1)
+    }
+}
+
+pub trait Accidental: Debug + Default {
+    /// Semitones relative to the natural note
+    fn offset(&self) -> i8;
+}
+
+#[derive(Debug, Default)]
+pub struct Natural;
+#[derive(Debug, Default)]
+pub struct Sharpened;
+#[derive(Debug, Default)]
+pub struct Flattened;
+#[derive(Debug, Default)]
+pub struct DoubleFlattened;
+
+impl Accidental for Natural {
+    fn offset(&self) -> i8 {
+        0
+    }
+}
+impl Accidental for Sharpened {
+    fn offset(&self) -> i8 {
+        1
+    }
+}
+impl Accidental for Flattened {
+    fn offset(&self) -> i8 {
+        -1
+    }
+}
+impl Accidental for DoubleFlattened {
+    fn offset(&self) -> i8 {
+        -2
     }
 }
 
@@ -60,7 +237,10 @@ macro_rules! impl_note {
     ($t:ty, $id:expr, $n:ty) => {
         impl Note for $t {
             type R = $t;
+            type Letter = $t;
+            type Accidental = Natural;
             type F = Flat<$t>;
+            type LF = Flat<$t>;
             type S = Sharp<$t>;
             type ST = $n;
             type T = <Sharp<$n> as Note>::R;
@@ -86,7 +266,10 @@ macro_rules! impl_note_for_sharp {
     (sharp(sharp($t:ty)) = $s:ty) => {
         impl Note for Sharp<$t> {
             type R = Sharp<$t>;
+            type Letter = $t;
+            type Accidental = Sharpened;
             type F = $t;
+            type LF = $t;
             type S = $s;
             type ST = $s;
             type T = Sharp<$s>;
@@ -114,7 +297,10 @@ where
     Sharp<N>: Note,
 {
     type R = <<Sharp<N> as Note>::S as Note>::R;
+    type Letter = <Self::R as Note>::Letter;
+    type Accidental = <Self::R as Note>::Accidental;
     type F = N::R;
+    type LF = <Self::R as Note>::LF;
     type S = <<<Sharp<N> as Note>::S as Note>::S as Note>::R;
     type ST = <<<Sharp<N> as Note>::S as Note>::ST as Note>::R;
     type T = <<<Sharp<N> as Note>::S as Note>::T as Note>::R;
@@ -132,7 +318,10 @@ where
     Flat<N>: Note,
 {
     type R = N::R;
+    type Letter = <N::R as Note>::Letter;
+    type Accidental = <N::R as Note>::Accidental;
     type F = <N::F as Note>::R;
+    type LF = <N::R as Note>::LF;
     type S = <N::S as Note>::R;
     type ST = <N::ST as Note>::R;
     type T = <N::T as Note>::R;
@@ -148,7 +337,10 @@ macro_rules! impl_note_for_flat {
     ($t:ty, $f:ty) => {
         impl Note for Flat<$t> {
             type R = Flat<$t>;
+            type Letter = $t;
+            type Accidental = Flattened;
             type F = $f;
+            type LF = Flat<Flat<$t>>;
             type S = $t;
             type ST = <<$t as Note>::ST as Note>::F;
             type T = <<$t as Note>::ST as Note>::R;
@@ -170,32 +362,76 @@ impl_note_for_flat!(G, F);
 impl_note_for_flat!(A, G);
 impl_note_for_flat!(B, A);
 
-impl<N> Note for Flat<Flat<N>>
-where
-    N: Note,
-    Flat<N>: Note,
-    Flat<N::R>: Note,
-{
-    type R = <<Flat<N> as Note>::F as Note>::R;
-    type F = <<Self::R as Note>::F as Note>::R;
-    type S = <N::F as Note>::R;
-    type ST = <N::F as Note>::R;
-    type T = N::R;
-    fn name(&self) -> String {
-        self.0.f().name()
-    }
-    fn id(&self) -> usize {
-        self.0.f().id()
-    }
+macro_rules! impl_note_for_double_flat {
+    ($t:ty) => {
+        impl Note for Flat<Flat<$t>> {
+            type R = Flat<Flat<$t>>;
+            type Letter = $t;
+            type Accidental = DoubleFlattened;
+            type F = <<Flat<$t> as Note>::F as Note>::F;
+            type LF = Self::F;
+            type S = Flat<$t>;
+            type ST = <Flat<<Flat<$t> as Note>::ST> as Note>::R;
+            type T = <Flat<<Flat<$t> as Note>::T> as Note>::R;
+            fn name(&self) -> String {
+                format!("{}𝄫 ", stringify!($t))
+            }
+            fn id(&self) -> usize {
+                (self.0.id() + 11) % 12
+            }
+        }
+    };
+}
+
+impl_note_for_double_flat!(C);
+impl_note_for_double_flat!(D);
+impl_note_for_double_flat!(E);
+impl_note_for_double_flat!(F);
+impl_note_for_double_flat!(G);
+impl_note_for_double_flat!(A);
+impl_note_for_double_flat!(B);
+
+macro_rules! impl_note_for_flat_flat {
+    ($w:ident) => {
+        impl<N> Note for Flat<Flat<$w<N>>>
+        where
+            N: Note,
+            $w<N>: Note,
+            Flat<$w<N>>: Note,
+            Flat<<$w<N> as Note>::R>: Note,
+        {
+            type R = <<Flat<$w<N>> as Note>::F as Note>::R;
+            type Letter = <Self::R as Note>::Letter;
+            type Accidental = <Self::R as Note>::Accidental;
+            type F = <<Self::R as Note>::F as Note>::R;
+            type LF = <Self::R as Note>::LF;
+            type S = <<$w<N> as Note>::F as Note>::R;
+            type ST = <<$w<N> as Note>::F as Note>::R;
+            type T = <$w<N> as Note>::R;
+            fn name(&self) -> String {
+                self.0.f().name()
+            }
+            fn id(&self) -> usize {
+                self.0.f().id()
+            }
+        }
+    };
 }
 
+// Triple accidentals are respelled, as the notes carry at most a double flat
+impl_note_for_flat_flat!(Flat);
+impl_note_for_flat_flat!(Sharp);
+
 impl<N> Note for Flat<Sharp<N>>
 where
     N: Note,
     Sharp<N>: Note,
 {
     type R = N::R;
+    type Letter = <N::R as Note>::Letter;
+    type Accidental = <N::R as Note>::Accidental;
     type F = <N::F as Note>::R;
+    type LF = <N::R as Note>::LF;
     type S = <N::S as Note>::R;
     type ST = <N::ST as Note>::R;
     type T = <N::T as Note>::R;
@@ -207,6 +443,33 @@ where
     }
 }
 
+/// Any note other than the empty `()` slot
+pub trait Pitched: Note {}
+impl Pitched for C {}
+impl Pitched for D {}
+impl Pitched for E {}
+impl Pitched for F {}
+impl Pitched for G {}
+impl Pitched for A {}
+impl Pitched for B {}
+impl<N: Note> Pitched for Sharp<N> where Sharp<N>: Note {}
+impl<N: Note> Pitched for Flat<N> where Flat<N>: Note {}
+
+/// The empty `()` slot, for chords that must not have a tone yet
+pub trait Unpitched: Note {}
+impl Unpitched for () {}
+
+/// `Self` if it is a pitched note, otherwise `O`
+pub trait OrElse<O: Note>: Note {
+    type R: Note;
+}
+impl<O: Note> OrElse<O> for () {
+    type R = O;
+}
+impl<N: Pitched, O: Note> OrElse<O> for N {
+    type R = N;
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -215,12 +478,49 @@ mod test {
 
     #[test_case(C, "C")]
     #[test_case(<Sharp<Sharp<D>>>::new(), "E")]
-    #[test_case(<Flat<Flat<G>>>::new(), "F")]
+    #[test_case(<Flat<Flat<G>>>::new(), "G𝄫 ")]
     #[test_case(<Flat<Flat<Flat<G>>>>::new(), "F♭ ")]
     fn test_note_name(note: impl Note, expected: &str) {
         assert_eq!(&note.name(), expected);
     }
 
+    #[test_case(<Sharp<C>>::new(), NamingStyle::English, "C♯")]
+    #[test_case(<Sharp<C>>::new(), NamingStyle::German, "Cis")]
+    #[test_case(<Flat<E>>::new(), NamingStyle::German, "Es")]
+    #[test_case(<Flat<B>>::new(), NamingStyle::German, "B")]
+    #[test_case(B, NamingStyle::German, "H")]
+    #[test_case(<Flat<B>>::new(), NamingStyle::Japanese, "変ロ")]
+    #[test_case(<Sharp<Sharp<D>>>::new(), NamingStyle::Japanese, "ホ")]
+    #[test_case(<Flat<Flat<B>>>::new(), NamingStyle::English, "B𝄫")]
+    #[test_case(<Flat<Flat<B>>>::new(), NamingStyle::German, "Heses")]
+    #[test_case(<Flat<Flat<B>>>::new(), NamingStyle::Japanese, "重変ロ")]
+    fn test_note_name_in(note: impl Note, style: NamingStyle, expected: &str) {
+        assert_eq!(&note.name_in(style), expected);
+    }
+
+    #[test_case(<Sharp<Sharp<E>>>::new())]
+    #[test_case(<Sharp<Sharp<B>>>::new())]
+    #[test_case(<Flat<Flat<C>>>::new())]
+    #[test_case(<Flat<Flat<F>>>::new())]
+    #[test_case(<Flat<Flat<Flat<G>>>>::new())]
+    fn test_pitch(note: impl Note) {
+        let pitch = note.pitch().unwrap();
+        assert_eq!(pitch.name(), note.name());
+        assert_eq!(pitch.id(), note.id());
+    }
+
+    #[test]
+    fn test_pitch_up() {
+        // E + augmented ninth is F𝄪, spelled G like the type-level notes
+        assert_eq!(C.pitch().unwrap().up(6, 9).name(), "A");
+        assert_eq!(E.pitch().unwrap().up(1, 3).name(), "G");
+        assert_eq!(F.pitch().unwrap().up(3, 6).name(), "B");
+        assert_eq!(F.pitch().unwrap().up(4, 6).name(), "C♭ ");
+        assert_eq!(C.pitch().unwrap().up(6, 10).flat().name(), "B𝄫 ");
+        assert_eq!(Flat::<B>::new().pitch().unwrap().flat().name(), "B𝄫 ");
+        assert_eq!(Flat::<Flat<B>>::new().pitch().unwrap().flat().name(), "A♭ ");
+    }
+
     #[test_case(E, Flat::<F>::new())]
     fn test_note_id(n1: impl Note, n2: impl Note) {
         assert_eq!(n1.id(), n2.id());
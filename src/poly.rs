@@ -0,0 +1,242 @@
+use crate::chord::*;
+use crate::key::*;
+use crate::note::*;
+use crate::symbol::*;
+use std::str::FromStr;
+
+/// Chord `Upper` stacked over chord `Lower`, written with a bar between them,
+/// "D|C7", rather than the slash of a bass note
+#[derive(Debug, Default)]
+pub struct Poly<Upper: Chord, Lower: Chord>(Upper, Lower);
+
+impl<Upper: Chord, Lower: Chord> Poly<Upper, Lower> {
+    pub fn upper(&self) -> &Upper {
+        &self.0
+    }
+    pub fn lower(&self) -> &Lower {
+        &self.1
+    }
+    pub fn name(&self) -> String {
+        format!("{}|{}", self.0.name().trim_end(), self.1.name())
+    }
+    pub fn value(&self) -> PolyValue {
+        PolyValue::new(self.0.value(), self.1.value())
+    }
+}
+
+impl<Upper, Lower, I> TransposeBy<I> for Poly<Upper, Lower>
+where
+    Upper: Chord + TransposeBy<I>,
+    Lower: Chord + TransposeBy<I>,
+    Upper::Output: Chord,
+    Lower::Output: Chord,
+{
+    type Output = Poly<Upper::Output, Lower::Output>;
+}
+
+/// Major triad a major second above a dominant seventh: 9, ♯11 and 13
+pub type UstII<R> = Poly<Major<<M2 as IntervalResolve<R>>::R>, Seventh<R>>;
+/// Major triad a minor third above a dominant seventh: ♯9, 5 and ♭7
+pub type UstFlatIII<R> = Poly<Major<<m3 as IntervalResolve<R>>::R>, Seventh<R>>;
+/// Major triad a diminished fifth above a dominant seventh: ♯11, ♭7 and ♭9
+pub type UstFlatV<R> = Poly<Major<<d5 as IntervalResolve<R>>::R>, Seventh<R>>;
+/// Major triad a major sixth above a dominant seventh: 13, ♭9 and 3
+pub type UstVI<R> = Poly<Major<<M6 as IntervalResolve<R>>::R>, Seventh<R>>;
+/// Major triad a minor sixth above a dominant seventh: ♭13, 1 and ♯9
+pub type UstFlatVI<R> = Poly<Major<<m6 as IntervalResolve<R>>::R>, Seventh<R>>;
+/// Major triad a minor second above a dominant seventh: ♭9, 11 and ♭13
+pub type UstFlatII<R> = Poly<Major<<m2 as IntervalResolve<R>>::R>, Seventh<R>>;
+
+/// Runtime counterpart of `Poly`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolyValue {
+    pub upper: ChordValue,
+    pub lower: ChordValue,
+}
+
+impl PolyValue {
+    pub fn new(upper: ChordValue, lower: ChordValue) -> Self {
+        Self { upper, lower }
+    }
+
+    pub fn name(&self) -> String {
+        format!("{}|{}", self.upper.name().trim_end(), self.lower.name())
+    }
+
+    /// Tones of the upper chord missing from the lower one, labelled by their
+    /// function over the lower root, e.g. 9, ♯11 and 13 for D|C7. They are heard
+    /// as tensions where one fits, so E♭ over C7 is its ♯9 rather than a ♭3
+    pub fn tensions(&self) -> Vec<ChordTone> {
+        let root = self.lower.root;
+        let lower = self.lower.slots();
+        self.upper
+            .tones()
+            .into_iter()
+            .filter(|tone| lower.iter().flatten().all(|p| p.id() != tone.pitch.id()))
+            .filter_map(|tone| {
+                let steps = (tone.pitch.letter + 7 - root.letter) % 7;
+                let semitones = (tone.pitch.id() + 12 - root.id()) % 12;
+                // Spellings without a slot, like C♯ over C, take an enharmonic one
+                (0..7)
+                    .find_map(|steps| Slot::of((steps, semitones)).filter(Slot::is_tension))
+                    .or_else(|| Slot::of((steps, semitones)))
+                    .or_else(|| (0..7).find_map(|steps| Slot::of((steps, semitones))))
+                    .map(|slot| ChordTone {
+                        slot,
+                        pitch: tone.pitch,
+                    })
+            })
+            .collect()
+    }
+
+    pub fn transpose(&self, interval: &impl Interval) -> Self {
+        Self::new(
+            self.upper.transpose(interval),
+            self.lower.transpose(interval),
+        )
+    }
+
+    /// Upper chord stacked over the lower one: a fraction in LaTeX, and two
+    /// lines of a `poly` span in HTML for a stylesheet to draw the bar between
+    pub fn format_markup(&self, style: SymbolStyle, markup: Markup) -> String {
+        let upper = format_chord_markup(&self.upper, style, markup);
+        let lower = format_chord_markup(&self.lower, style, markup);
+        match markup {
+            Markup::Plain => format!("{upper}|{lower}"),
+            Markup::Html => {
+                format!("<span class=\"poly\"><span>{upper}</span><span>{lower}</span></span>")
+            }
+            Markup::Latex => format!("\\frac{{{upper}}}{{{lower}}}"),
+        }
+    }
+}
+
+impl FromStr for PolyValue {
+    type Err = ParseChordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('|') {
+            Some((upper, lower)) => Ok(Self::new(parse_chord(upper)?, parse_chord(lower)?)),
+            None => Err(ParseChordError::MissingPolyBar(s.into())),
+        }
+    }
+}
+
+/// Upper-structure triads over a dominant seventh, by the degree of their root
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UpperStructure {
+    II,
+    FlatIII,
+    FlatV,
+    VI,
+    FlatVI,
+    FlatII,
+}
+
+impl UpperStructure {
+    pub const ALL: [UpperStructure; 6] = [
+        UpperStructure::II,
+        UpperStructure::FlatIII,
+        UpperStructure::FlatV,
+        UpperStructure::VI,
+        UpperStructure::FlatVI,
+        UpperStructure::FlatII,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            UpperStructure::II => "II",
+            UpperStructure::FlatIII => "♭III",
+            UpperStructure::FlatV => "♭V",
+            UpperStructure::VI => "VI",
+            UpperStructure::FlatVI => "♭VI",
+            UpperStructure::FlatII => "♭II",
+        }
+    }
+
+    /// Letter steps and semitones of the triad root above the dominant
+    fn interval(&self) -> (usize, usize) {
+        match self {
+            UpperStructure::II => (1, 2),
+            UpperStructure::FlatIII => (2, 3),
+            UpperStructure::FlatV => (4, 6),
+            UpperStructure::VI => (5, 9),
+            UpperStructure::FlatVI => (5, 8),
+            UpperStructure::FlatII => (1, 1),
+        }
+    }
+
+    /// This triad over the dominant seventh on `root`, like the `Ust*` types
+    pub fn over(&self, root: Pitch) -> PolyValue {
+        let (steps, semitones) = self.interval();
+        PolyValue::new(
+            ChordValue::new(root.up(steps, semitones), Quality::Major),
+            ChordValue::new(root, Quality::Seventh),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    fn functions(tones: &[ChordTone]) -> Vec<&'static str> {
+        tones.iter().map(|t| t.function()).collect()
+    }
+
+    #[test]
+    fn test_poly() {
+        let chord = <Poly<Major<D>, Seventh<C>>>::default();
+        assert_eq!(chord.name(), "D|C7");
+        assert_eq!(chord.upper().name(), "D");
+        assert_eq!(chord.value().lower, <Seventh<C>>::default().value());
+        assert_eq!(<Poly<MinorSeventh<E>, Major<F>>>::default().name(), "Em7|F");
+        assert_eq!(<UstFlatVI<D>>::default().name(), "B♭|D7");
+        let _: Poly<Major<E>, Seventh<D>> = <Transpose<UstII<C>, M2>>::default();
+    }
+
+    #[test_case(UpperStructure::II,      <UstII<C>>::default().value(),      "D|C7",   &["9", "♯11", "13"])]
+    #[test_case(UpperStructure::FlatIII, <UstFlatIII<C>>::default().value(), "E♭|C7",  &["♯9"])]
+    #[test_case(UpperStructure::FlatV,   <UstFlatV<C>>::default().value(),   "G♭|C7",  &["♯11", "♭9"])]
+    #[test_case(UpperStructure::VI,      <UstVI<C>>::default().value(),      "A|C7",   &["13", "♭9"])]
+    #[test_case(UpperStructure::FlatVI,  <UstFlatVI<C>>::default().value(),  "A♭|C7",  &["♭13", "♯9"])]
+    #[test_case(UpperStructure::FlatII,  <UstFlatII<C>>::default().value(),  "D♭|C7",  &["♭9", "11", "♭13"])]
+    fn test_upper_structure(ust: UpperStructure, chord: PolyValue, name: &str, tensions: &[&str]) {
+        assert_eq!(ust.over(chord.lower.root), chord);
+        assert_eq!(chord.name(), name);
+        assert_eq!(functions(&chord.tensions()), tensions);
+    }
+
+    #[test]
+    fn test_markup() {
+        let chord = UpperStructure::II.over(Pitch::new(0, 0));
+        assert_eq!(
+            chord.format_markup(SymbolStyle::Jazz, Markup::Plain),
+            "D|C7"
+        );
+        assert_eq!(
+            chord.format_markup(SymbolStyle::Jazz, Markup::Html),
+            "<span class=\"poly\"><span>D</span><span>C<sup>7</sup></span></span>"
+        );
+        assert_eq!(
+            chord.format_markup(SymbolStyle::Jazz, Markup::Latex),
+            "\\frac{D}{C^{7}}"
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("D|C7".parse(), Ok(<UstII<C>>::default().value()));
+        assert_eq!(
+            "Bb|C7"
+                .parse::<PolyValue>()
+                .map(|c| c.transpose(&M2).name()),
+            Ok("C|D7".into())
+        );
+        assert_eq!(
+            "D".parse::<PolyValue>(),
+            Err(ParseChordError::MissingPolyBar("D".into()))
+        );
+        assert!("D|H7".parse::<PolyValue>().is_err());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::chord::*;
+use crate::diatonic::*;
 use crate::key::*;
 
 pub trait Progression {
@@ -8,14 +9,24 @@ pub trait Progression {
     }
 }
 
+/// Holds when every chord of the progression is `InKey<K>`, so chromatic
+/// chords must be marked `Chromatic`, e.g. `PopPunkProgression<K>: ProgressionInKey<K>`
+pub trait ProgressionInKey<K: Key>: Progression {}
+impl<K: Key, P: Progression> ProgressionInKey<K> for P where P::Progression: InKey<K> {}
+
 #[derive(Debug, Default)]
 pub struct PopPunkProgression<K: Key>(K);
 
 impl<K> Progression for PopPunkProgression<K>
 where
-    K: Key,
+    K: DiatonicChords,
 {
-    type Progression = (Major<K::I>, Major<K::V>, Minor<K::VI>, Major<K::IV>);
+    type Progression = (
+        DiatonicTriad<K, I>,
+        DiatonicTriad<K, V>,
+        DiatonicTriad<K, VI>,
+        DiatonicTriad<K, IV>,
+    );
 }
 
 #[derive(Debug, Default)]
@@ -23,9 +34,14 @@ pub struct KomuroProgression<K: Key>(K);
 
 impl<K> Progression for KomuroProgression<K>
 where
-    K: Key,
+    K: DiatonicChords,
 {
-    type Progression = (Minor<K::VI>, Major<K::IV>, Major<K::V>, Major<K::I>);
+    type Progression = (
+        DiatonicTriad<K, VI>,
+        DiatonicTriad<K, IV>,
+        DiatonicTriad<K, V>,
+        DiatonicTriad<K, I>,
+    );
 }
 
 #[derive(Debug, Default)]
@@ -33,17 +49,17 @@ pub struct CanonProgression<K: Key>(K);
 
 impl<K> Progression for CanonProgression<K>
 where
-    K: Key,
+    K: DiatonicChords,
 {
     type Progression = (
-        Major<K::I>,
-        Major<K::V>,
-        Minor<K::VI>,
-        Minor<K::III>,
-        Major<K::IV>,
-        Major<K::I>,
-        Major<K::IV>,
-        Major<K::V>,
+        DiatonicTriad<K, I>,
+        DiatonicTriad<K, V>,
+        DiatonicTriad<K, VI>,
+        DiatonicTriad<K, III>,
+        DiatonicTriad<K, IV>,
+        DiatonicTriad<K, I>,
+        DiatonicTriad<K, IV>,
+        DiatonicTriad<K, V>,
     );
 }
 
@@ -52,13 +68,13 @@ pub struct RoyalRoadProgression<K: Key>(K);
 
 impl<K> Progression for RoyalRoadProgression<K>
 where
-    K: Key,
+    K: DiatonicChords,
 {
     type Progression = (
-        MajorSeventh<K::IV>,
-        Seventh<K::V>,
-        MinorSeventh<K::III>,
-        Minor<K::VI>,
+        DiatonicSeventh<K, IV>,
+        DiatonicSeventh<K, V>,
+        DiatonicSeventh<K, III>,
+        DiatonicTriad<K, VI>,
     );
 }
 
@@ -67,21 +83,43 @@ pub struct JustTheTwoOfUsProgression<K: Key>(K);
 
 impl<K> Progression for JustTheTwoOfUsProgression<K>
 where
-    K: Key,
+    K: DiatonicChords,
 {
     type Progression = (
-        MajorSeventh<K::IV>,
-        Seventh<K::III>,
-        MinorSeventh<K::VI>,
-        MinorSeventh<K::V>,
-        Seventh<K::I>,
+        DiatonicSeventh<K, IV>,
+        Chromatic<SecondaryDominant<K, VI>>,
+        DiatonicSeventh<K, VI>,
+        // The v7, borrowed from the parallel minor in major keys and diatonic in minor ones
+        BorrowedFrom<Aeolian, MinorSeventh<K::V>>,
+        Chromatic<SecondaryDominant<K, IV>>,
     );
 }
 
+macro_rules! impl_transpose_for_progression {
+    ($($t:ident),+) => {
+        $(impl<K, I> TransposeBy<I> for $t<K>
+        where
+            K: Key + TransposeBy<I>,
+            K::Output: Key,
+        {
+            type Output = $t<K::Output>;
+        })+
+    };
+}
+
+impl_transpose_for_progression!(
+    PopPunkProgression,
+    KomuroProgression,
+    CanonProgression,
+    RoyalRoadProgression,
+    JustTheTwoOfUsProgression
+);
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::note::*;
+    use crate::roman::RomanNumeral;
 
     #[test]
     fn test_pop_punk_progression() {
@@ -190,4 +228,59 @@ mod test {
             prog.4.notes_tuple(),
         );
     }
+
+    #[test]
+    fn test_transpose_progression() {
+        let _: CanonProgression<MajorKey<D>> =
+            <Transpose<CanonProgression<MajorKey<C>>, M2>>::default();
+        let prog = <Transpose<RoyalRoadProgression<MajorKey<C>>, M3>>::default().prog();
+        assert_eq!(prog.0.name(), "Amaj7");
+        assert_eq!(prog.3.name(), "C♯ m");
+    }
+
+    #[test]
+    fn test_secondary_dominants_in_progression() {
+        let prog = <JustTheTwoOfUsProgression<MajorKey<Flat<D>>>>::default().prog();
+        assert_eq!(
+            [
+                prog.0.name(),
+                prog.1.name(),
+                prog.2.name(),
+                prog.3.name(),
+                prog.4.name()
+            ],
+            ["G♭ maj7", "F7", "B♭ m7", "A♭ m7", "D♭ 7"]
+        );
+        let prog = <JustTheTwoOfUsProgression<MajorKey<C>>>::default().prog();
+        assert_eq!(RomanNumeral::<MajorKey<C>>::roman_numeral(&prog.1), "V7/vi");
+        assert_eq!(RomanNumeral::<MajorKey<C>>::roman_numeral(&prog.4), "V7/IV");
+    }
+
+    #[test]
+    fn test_diatonic_progression_in_minor() {
+        let prog = <PopPunkProgression<MinorKey<C>>>::default().prog();
+        assert_eq!(
+            [prog.0.name(), prog.1.name(), prog.2.name(), prog.3.name()],
+            ["Cm", "Gm", "A♭ ", "Fm"]
+        );
+        let prog = <RoyalRoadProgression<MinorKey<A>>>::default().prog();
+        assert_eq!(
+            [prog.0.name(), prog.1.name(), prog.2.name(), prog.3.name()],
+            ["Dm7", "Em7", "Cmaj7", "F"]
+        );
+    }
+
+    fn in_key<K: Key, P: ProgressionInKey<K>>() {}
+
+    #[test]
+    fn test_progressions_in_key() {
+        in_key::<MajorKey<C>, PopPunkProgression<MajorKey<C>>>();
+        in_key::<MinorKey<Sharp<F>>, KomuroProgression<MinorKey<Sharp<F>>>>();
+        in_key::<MajorKey<Flat<E>>, CanonProgression<MajorKey<Flat<E>>>>();
+        in_key::<MinorKey<A>, RoyalRoadProgression<MinorKey<A>>>();
+        // Secondary dominants are marked `Chromatic`, and the v7 is borrowed from Aeolian
+        in_key::<MajorKey<Flat<D>>, JustTheTwoOfUsProgression<MajorKey<Flat<D>>>>();
+        in_key::<MinorKey<A>, JustTheTwoOfUsProgression<MinorKey<A>>>();
+        in_key::<MajorKey<D>, Transpose<CanonProgression<MajorKey<C>>, M2>>();
+    }
 }
@@ -0,0 +1,206 @@
+use crate::chord::*;
+use crate::note::*;
+
+/// Modifier stacks tried on every quality, with the penalty they add to the rank
+const VARIANTS: &[(&[Modifier], usize)] = &[
+    (&[], 0),
+    (&[Modifier::Omit5], 1),
+    (&[Modifier::Sus4], 1),
+    (&[Modifier::Sus2], 1),
+    (&[Modifier::Add9], 1),
+    (&[Modifier::Omit1], 3),
+    (&[Modifier::Omit1, Modifier::Omit5], 4),
+];
+
+/// Simpler qualities rank first
+fn complexity(quality: Quality) -> usize {
+    match quality {
+        Quality::Major | Quality::Minor | Quality::Power => 0,
+        Quality::Aug | Quality::Dim => 1,
+        Quality::Seventh | Quality::MajorSeventh | Quality::MinorSeventh => 1,
+        Quality::Sixth | Quality::MinorSixth => 2,
+        Quality::HalfDiminished | Quality::DiminishedSeventh => 2,
+        Quality::MinorMajorSeventh | Quality::AugmentedSeventh | Quality::AugmentedMajorSeventh => {
+            3
+        }
+        Quality::Ninth | Quality::MajorNinth | Quality::MinorNinth | Quality::SixNine => 3,
+        // Chords not built in thirds are a fallback
+        Quality::Quartal | Quality::SoWhat | Quality::Quintal | Quality::Cluster => 5,
+        _ => 4,
+    }
+}
+
+/// Suspensions only replace a major third, add9 only extends a plain triad,
+/// and power, quartal, quintal and cluster chords are never modified
+fn applies(quality: Quality, modifiers: &[Modifier]) -> bool {
+    if matches!(
+        quality,
+        Quality::Power | Quality::Quartal | Quality::SoWhat | Quality::Quintal | Quality::Cluster
+    ) {
+        return modifiers.is_empty();
+    }
+    let slots = quality.slots();
+    modifiers.iter().all(|modifier| match modifier {
+        Modifier::Sus2 | Modifier::Sus4 => slots.contains(&Slot::M3) && slots.contains(&Slot::P5),
+        Modifier::Add9 => matches!(quality, Quality::Major | Quality::Minor),
+        _ => true,
+    })
+}
+
+fn pitch_classes(pitches: impl IntoIterator<Item = Pitch>) -> [bool; 12] {
+    let mut set = [false; 12];
+    for pitch in pitches {
+        set[pitch.id()] = true;
+    }
+    set
+}
+
+/// Candidate chords whose tones are exactly `notes`, best match first.
+///
+/// Notes are compared by pitch class, so enharmonic spellings match.
+/// A bass other than the root gives slash chords, and candidates may omit the fifth or the root.
+/// Augmented sixths are named by their function in a key, so are left to `roman_numeral`.
+pub fn recognize(notes: &[Pitch], bass: Option<Pitch>) -> Vec<ChordValue> {
+    let target = pitch_classes(notes.iter().copied().chain(bass));
+    let spelling = |id: usize| {
+        notes
+            .iter()
+            .chain(bass.iter())
+            .find(|p| p.id() == id)
+            .copied()
+            .unwrap_or_else(|| Pitch::from_id(id))
+    };
+
+    let mut candidates = vec![];
+    for id in 0..12 {
+        let root = spelling(id);
+        for quality in Quality::ALL.into_iter().filter(|q| !q.is_augmented_sixth()) {
+            for (modifiers, penalty) in VARIANTS {
+                if !applies(quality, modifiers) {
+                    continue;
+                }
+                let mut chord = ChordValue::new(root, quality);
+                for modifier in *modifiers {
+                    chord = chord.with(*modifier);
+                }
+                if pitch_classes(chord.slots().into_iter().flatten()) != target {
+                    continue;
+                }
+                let mut rank = complexity(quality) + penalty;
+                if let Some(bass) = bass.filter(|b| b.id() != id) {
+                    chord = chord.over(bass);
+                    rank += 1;
+                }
+                candidates.push((rank, chord));
+            }
+        }
+    }
+    candidates.sort_by_key(|(rank, chord)| (*rank, chord.bass.is_some()));
+    let mut names = vec![];
+    candidates
+        .into_iter()
+        .map(|(_, chord)| chord)
+        .filter(|chord| {
+            let name = chord.name();
+            let new = !names.contains(&name);
+            names.push(name);
+            new
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Pitch of a note name such as "E", "Ab" or "F#"
+    fn pitch(name: &str) -> Pitch {
+        let letter = "CDEFGAB".find(&name[..1]).unwrap();
+        let accidental = match &name[1..] {
+            "b" => -1,
+            "#" => 1,
+            _ => 0,
+        };
+        Pitch::new(letter, accidental)
+    }
+
+    fn pitches(notes: &str) -> Vec<Pitch> {
+        notes.split_whitespace().map(pitch).collect()
+    }
+
+    fn names(chords: &[ChordValue]) -> Vec<String> {
+        chords.iter().map(|c| c.name()).collect()
+    }
+
+    #[test]
+    fn test_recognize_triad() {
+        let candidates = recognize(&pitches("C E G"), None);
+        assert_eq!(candidates[0].name(), "C");
+        assert_eq!(candidates[0].quality, Quality::Major);
+    }
+
+    #[test]
+    fn test_recognize_inversion() {
+        let candidates = recognize(&pitches("C E G"), Some(pitch("E")));
+        assert_eq!(candidates[0].name(), "C/E");
+    }
+
+    #[test]
+    fn test_recognize_shared_pitch_set() {
+        let names = names(&recognize(&pitches("C E G A"), None));
+        assert_eq!(names[0], "Am7");
+        assert!(names.contains(&"C6".to_string()));
+        let candidates = recognize(&pitches("C E G A"), Some(pitch("C")));
+        assert_eq!(candidates[0].name(), "C6");
+        assert_eq!(candidates[1].name(), "Am7/C");
+    }
+
+    #[test]
+    fn test_recognize_omit5() {
+        let candidates = recognize(&pitches("G B F"), None);
+        assert_eq!(candidates[0].name(), "G7omit5");
+    }
+
+    #[test]
+    fn test_recognize_rootless() {
+        // Rootless C9 voicing: E B♭ D, plus G
+        let names = names(&recognize(&pitches("E G Bb D"), None));
+        assert!(names.contains(&"C9omit1".to_string()));
+        assert_eq!(names[0], "Em7♭5");
+    }
+
+    #[test]
+    fn test_recognize_enharmonic() {
+        // G♯ written as A♭ still gives an E major chord
+        let candidates = recognize(&pitches("E Ab B"), None);
+        assert_eq!(candidates[0].name(), "E");
+        assert_eq!(candidates[0].notes(), "E   G♯  B  ");
+    }
+
+    #[test]
+    fn test_recognize_non_tertian() {
+        assert_eq!(recognize(&pitches("C G"), None)[0].name(), "C5");
+        let so_what = names(&recognize(&pitches("E A D G B"), None));
+        assert_eq!(so_what[0], "G6/9");
+        assert!(so_what.contains(&"ESoWhat".to_string()));
+        assert_eq!(recognize(&pitches("C Db D"), None)[0].name(), "Ccluster");
+        let quartal = names(&recognize(&pitches("C F Bb"), Some(pitch("C"))));
+        assert!(quartal.contains(&"Cquartal".to_string()));
+        let quintal = names(&recognize(&pitches("C G D"), None));
+        assert_eq!(quintal[0], "Csus2");
+        assert!(quintal.contains(&"Cquintal".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_no_augmented_sixth() {
+        assert_eq!(names(&recognize(&pitches("G B D F"), None)), ["G7"]);
+        assert!(!names(&recognize(&pitches("Ab C D F#"), None))
+            .iter()
+            .any(|name| name.ends_with("+6")));
+    }
+
+    #[test]
+    fn test_recognize_nothing() {
+        assert!(recognize(&pitches("C Db D Eb"), None).is_empty());
+    }
+}
@@ -0,0 +1,281 @@
+use crate::chord::*;
+use crate::key::*;
+
+/// Roman numeral of a chord in the key `K`, e.g. `Seventh<G>` in `MajorKey<C>` is "V7"
+pub trait RomanNumeral<K: Key>: Chord {
+    fn roman_numeral(&self) -> String {
+        roman_numeral(&self.value(), &K::default())
+    }
+}
+
+impl<K: Key, C: Chord> RomanNumeral<K> for C where C::R: DegreeIn<K> {}
+
+const TRIADS: [Quality; 4] = [Quality::Major, Quality::Minor, Quality::Aug, Quality::Dim];
+const SEVENTHS: [Quality; 8] = [
+    Quality::Seventh,
+    Quality::MajorSeventh,
+    Quality::MinorSeventh,
+    Quality::HalfDiminished,
+    Quality::DiminishedSeventh,
+    Quality::MinorMajorSeventh,
+    Quality::AugmentedSeventh,
+    Quality::AugmentedMajorSeventh,
+];
+
+fn has(chord: &ChordValue, slot: Slot) -> bool {
+    chord.slots()[slot.index()].is_some()
+}
+
+/// Minor and diminished chords take a lowercase numeral
+fn is_lower(chord: &ChordValue) -> bool {
+    has(chord, Slot::m3) && !has(chord, Slot::M3)
+}
+
+/// Quality marks after the numeral, with figured bass for inverted triads and seventh chords
+fn figures(chord: &ChordValue) -> String {
+    let quality = match chord.quality {
+        Quality::Major | Quality::Minor => "",
+        Quality::Aug => "+",
+        Quality::Dim => "°",
+        Quality::Seventh | Quality::MinorSeventh => "7",
+        Quality::MajorSeventh | Quality::MinorMajorSeventh => "maj7",
+        Quality::HalfDiminished => "ø7",
+        Quality::DiminishedSeventh => "°7",
+        Quality::AugmentedSeventh => "+7",
+        Quality::AugmentedMajorSeventh => "+maj7",
+        // A bare 6 would read as a first inversion
+        Quality::Sixth | Quality::MinorSixth => "(add6)",
+        quality if is_lower(chord) && !quality.suffix().starts_with("maj") => {
+            quality.suffix().trim_start_matches('m')
+        }
+        quality => quality.suffix(),
+    };
+    let inversion = chord
+        .bass
+        .filter(|_| chord.modifiers.is_empty())
+        .and_then(|bass| {
+            Slot::ALL
+                .iter()
+                .zip(chord.slots())
+                .find(|(_, p)| p.is_some_and(|p| p.id() == bass.id()))
+                .map(|(slot, _)| *slot)
+        });
+    let mut figures = match inversion {
+        Some(Slot::m3 | Slot::M3) if TRIADS.contains(&chord.quality) => format!("{quality}6"),
+        Some(Slot::d5 | Slot::P5 | Slot::A5) if TRIADS.contains(&chord.quality) => {
+            format!("{quality}6/4")
+        }
+        Some(slot) if SEVENTHS.contains(&chord.quality) => {
+            let figure = match slot {
+                Slot::m3 | Slot::M3 => "6/5",
+                Slot::d5 | Slot::P5 | Slot::A5 => "4/3",
+                _ => "4/2",
+            };
+            format!("{}{figure}", quality.trim_end_matches('7'))
+        }
+        _ => quality.into(),
+    };
+    for modifier in &chord.modifiers {
+        figures += modifier.suffix();
+    }
+    figures
+}
+
+fn numeral(degree: usize, lower: bool) -> String {
+    let numeral = ["I", "II", "III", "IV", "V", "VI", "VII"][degree - 1];
+    if lower {
+        numeral.to_lowercase()
+    } else {
+        numeral.into()
+    }
+}
+
+/// Augmented sixth chords by quality, or by their pitch classes above the tonic when
+/// spelled with the ♯4: ♭6, 1 and ♯4, with 2 for the French and ♭3 for the German sixth.
+/// A ♭VI7 has a ♭5 in place of the ♯4, so "A♭7" in C stays a ♭VI7
+fn augmented_sixth<K: Key>(chord: &ChordValue, key: &K) -> Option<&'static str> {
+    match chord.quality {
+        Quality::ItalianSixth => return Some("It+6"),
+        Quality::FrenchSixth => return Some("Fr+6"),
+        Quality::GermanSixth => return Some("Ger+6"),
+        _ => {}
+    }
+    let tonic = key.pitches()[0];
+    let slots = chord.slots();
+    if !slots.iter().flatten().any(|p| *p == tonic.up(3, 6)) {
+        return None;
+    }
+    let tonic = tonic.id();
+    let mut above: Vec<usize> = slots
+        .iter()
+        .flatten()
+        .map(|p| (p.id() + 12 - tonic) % 12)
+        .collect();
+    above.sort_unstable();
+    above.dedup();
+    match above[..] {
+        [0, 6, 8] => Some("It+6"),
+        [0, 2, 6, 8] => Some("Fr+6"),
+        [0, 3, 6, 8] => Some("Ger+6"),
+        _ => None,
+    }
+}
+
+/// Numeral of the chord as dominant or leading-tone chord of a diatonic degree other than the tonic
+fn secondary<K: Key>(chord: &ChordValue, key: &K) -> Option<String> {
+    let scale = key.pitches();
+    let (function, target) =
+        if has(chord, Slot::M3) && !has(chord, Slot::A5) && !has(chord, Slot::M7) {
+            (5, chord.root.up(3, 5))
+        } else if matches!(
+            chord.quality,
+            Quality::Dim | Quality::HalfDiminished | Quality::DiminishedSeventh
+        ) {
+            (7, chord.root.up(1, 1))
+        } else {
+            return None;
+        };
+    let target = key.degree_of_pitch(target);
+    let d = target.degree - 1;
+    let above = |n: usize| (scale[(d + n) % 7].id() + 12 - scale[d].id()) % 12;
+    // Diminished triads are not tonicized
+    if target.alteration != 0 || target.degree == 1 || above(4) != 7 {
+        return None;
+    }
+    Some(format!(
+        "{}{}/{}",
+        numeral(function, is_lower(chord)),
+        figures(chord),
+        numeral(target.degree, above(2) == 3)
+    ))
+}
+
+/// Roman numeral of `chord` in `key`, e.g. "V7", "ii°", "♭VI", "viiø7", "V7/V", "N6" or "It+6".
+///
+/// Degrees are named against the notes of the key, so the raised sixth and seventh
+/// of a minor key take a sharp, as in "♯vii°7" in C minor.
+/// Slash basses other than inversions of triads and seventh chords are not shown.
+pub fn roman_numeral<K: Key>(chord: &ChordValue, key: &K) -> String {
+    if let Some(label) = augmented_sixth(chord, key) {
+        return label.into();
+    }
+    let degree = key.degree_of_pitch(chord.root);
+    if degree.degree == 2
+        && degree.alteration == -1
+        && chord.quality == Quality::Major
+        && chord.modifiers.is_empty()
+    {
+        return match figures(chord).as_str() {
+            "6" => "N6".into(),
+            _ => "N".into(),
+        };
+    }
+    let scale = key.pitches();
+    let diatonic = chord
+        .slots()
+        .iter()
+        .flatten()
+        .all(|p| scale.iter().any(|s| s.id() == p.id()));
+    if !diatonic {
+        if let Some(label) = secondary(chord, key) {
+            return label;
+        }
+    }
+    let accidental = match degree.alteration {
+        a if a > 0 => "♯".repeat(a as usize),
+        a => "♭".repeat(a.unsigned_abs() as usize),
+    };
+    format!(
+        "{accidental}{}{}",
+        numeral(degree.degree, is_lower(chord)),
+        figures(chord)
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::note::*;
+    use crate::symbol::parse_chord;
+    use test_case::test_case;
+
+    #[test_case("C", "I")]
+    #[test_case("Dm", "ii")]
+    #[test_case("Bdim", "vii°")]
+    #[test_case("G7", "V7")]
+    #[test_case("Bm7♭5", "viiø7")]
+    #[test_case("Fmaj7", "IVmaj7")]
+    #[test_case("Dm9", "ii9")]
+    #[test_case("A♭", "♭VI")]
+    #[test_case("B♭7", "♭VII7")]
+    #[test_case("C/E", "I6")]
+    #[test_case("C/G", "I6/4")]
+    #[test_case("G7/B", "V6/5")]
+    #[test_case("G7/D", "V4/3")]
+    #[test_case("G7/F", "V4/2")]
+    #[test_case("D7", "V7/V")]
+    #[test_case("D", "V/V")]
+    #[test_case("E7", "V7/vi")]
+    #[test_case("C7", "V7/IV")]
+    #[test_case("F♯dim7", "vii°7/V")]
+    #[test_case("D♭/F", "N6")]
+    #[test_case("A♭It+6", "It+6")]
+    #[test_case("A♭Fr+6", "Fr+6")]
+    #[test_case("A♭Ger+6", "Ger+6")]
+    #[test_case("D7♭5/A♭", "Fr+6")]
+    #[test_case("A♭7", "♭VI7")]
+    #[test_case("A♭7omit5", "♭VI7omit5")]
+    #[test_case("Gsus4", "Vsus4")]
+    #[test_case("C6", "I(add6)")]
+    #[test_case("Dm6", "ii(add6)")]
+    fn test_major_key(chord: &str, numeral: &str) {
+        let chord = parse_chord(chord).unwrap();
+        assert_eq!(roman_numeral(&chord, &<MajorKey<C>>::default()), numeral);
+    }
+
+    #[test_case("Cm", "i")]
+    #[test_case("Ddim", "ii°")]
+    #[test_case("E♭", "III")]
+    #[test_case("A♭", "VI")]
+    #[test_case("G7", "V7")]
+    #[test_case("Bdim7", "♯vii°7")]
+    #[test_case("A", "♯VI")]
+    #[test_case("Cm6", "i(add6)")]
+    #[test_case("Cm/E♭", "i6")]
+    #[test_case("B♭7", "VII7")]
+    #[test_case("D♭", "N")]
+    fn test_minor_key(chord: &str, numeral: &str) {
+        let chord = parse_chord(chord).unwrap();
+        assert_eq!(roman_numeral(&chord, &<MinorKey<C>>::default()), numeral);
+    }
+
+    #[test]
+    fn test_type_level() {
+        assert_eq!(
+            <Seventh<G> as RomanNumeral<MajorKey<C>>>::roman_numeral(&Default::default()),
+            "V7"
+        );
+        assert_eq!(
+            RomanNumeral::<MajorKey<Flat<E>>>::roman_numeral(&<HalfDiminished<D>>::default()),
+            "viiø7"
+        );
+        assert_eq!(
+            RomanNumeral::<MinorKey<E>>::roman_numeral(&<Inversion<Major<B>, First>>::default()),
+            "V6"
+        );
+        assert_eq!(
+            RomanNumeral::<MajorKey<C>>::roman_numeral(&<FrenchSixth<MajorKey<C>>>::default()),
+            "Fr+6"
+        );
+        assert_eq!(
+            RomanNumeral::<MinorKey<A>>::roman_numeral(
+                &<crate::diatonic::Neapolitan<MinorKey<A>>>::default()
+            ),
+            "N6"
+        );
+        assert_eq!(
+            RomanNumeral::<MajorKey<C>>::roman_numeral(&<Sharp5<Major<F>>>::default()),
+            "IV♯5"
+        );
+    }
+}
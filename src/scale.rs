@@ -0,0 +1,311 @@
+use crate::chord::*;
+use crate::note::*;
+
+/// Scale played over a chord, built on the chord root
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scale {
+    Ionian,
+    Dorian,
+    Mixolydian,
+    Aeolian,
+    Lydian,
+    Phrygian,
+    Locrian,
+    LocrianNatural2,
+    MelodicMinor,
+    LydianDominant,
+    WholeTone,
+    Altered,
+    WholeHalfDiminished,
+    HalfWholeDiminished,
+    HarmonicMinor,
+    PhrygianDominant,
+    MixolydianFlat13,
+    LydianAugmented,
+}
+
+impl Scale {
+    /// Every scale, most common choice first
+    pub const ALL: [Scale; 18] = [
+        Scale::Ionian,
+        Scale::Dorian,
+        Scale::Mixolydian,
+        Scale::Aeolian,
+        Scale::Lydian,
+        Scale::Phrygian,
+        Scale::Locrian,
+        Scale::LocrianNatural2,
+        Scale::MelodicMinor,
+        Scale::LydianDominant,
+        Scale::WholeTone,
+        Scale::Altered,
+        Scale::WholeHalfDiminished,
+        Scale::HalfWholeDiminished,
+        Scale::HarmonicMinor,
+        Scale::PhrygianDominant,
+        Scale::MixolydianFlat13,
+        Scale::LydianAugmented,
+    ];
+
+    /// Letter steps and semitones of each tone above the root. Altered and
+    /// diminished scales are spelled as chord functions (♭9 and ♯9, not ♭2 and ♭3)
+    pub fn intervals(&self) -> &'static [(usize, usize)] {
+        match self {
+            Scale::Ionian => &[(0, 0), (1, 2), (2, 4), (3, 5), (4, 7), (5, 9), (6, 11)],
+            Scale::Dorian => &[(0, 0), (1, 2), (2, 3), (3, 5), (4, 7), (5, 9), (6, 10)],
+            Scale::Mixolydian => &[(0, 0), (1, 2), (2, 4), (3, 5), (4, 7), (5, 9), (6, 10)],
+            Scale::Aeolian => &[(0, 0), (1, 2), (2, 3), (3, 5), (4, 7), (5, 8), (6, 10)],
+            Scale::Lydian => &[(0, 0), (1, 2), (2, 4), (3, 6), (4, 7), (5, 9), (6, 11)],
+            Scale::Phrygian => &[(0, 0), (1, 1), (2, 3), (3, 5), (4, 7), (5, 8), (6, 10)],
+            Scale::Locrian => &[(0, 0), (1, 1), (2, 3), (3, 5), (4, 6), (5, 8), (6, 10)],
+            Scale::LocrianNatural2 => &[(0, 0), (1, 2), (2, 3), (3, 5), (4, 6), (5, 8), (6, 10)],
+            Scale::MelodicMinor => &[(0, 0), (1, 2), (2, 3), (3, 5), (4, 7), (5, 9), (6, 11)],
+            Scale::LydianDominant => &[(0, 0), (1, 2), (2, 4), (3, 6), (4, 7), (5, 9), (6, 10)],
+            Scale::Altered => &[(0, 0), (1, 1), (1, 3), (2, 4), (3, 6), (5, 8), (6, 10)],
+            Scale::HalfWholeDiminished => &[
+                (0, 0),
+                (1, 1),
+                (1, 3),
+                (2, 4),
+                (3, 6),
+                (4, 7),
+                (5, 9),
+                (6, 10),
+            ],
+            Scale::WholeHalfDiminished => &[
+                (0, 0),
+                (1, 2),
+                (2, 3),
+                (3, 5),
+                (4, 6),
+                (5, 8),
+                (6, 9),
+                (6, 11),
+            ],
+            Scale::WholeTone => &[(0, 0), (1, 2), (2, 4), (3, 6), (4, 8), (6, 10)],
+            Scale::HarmonicMinor => &[(0, 0), (1, 2), (2, 3), (3, 5), (4, 7), (5, 8), (6, 11)],
+            Scale::PhrygianDominant => &[(0, 0), (1, 1), (2, 4), (3, 5), (4, 7), (5, 8), (6, 10)],
+            Scale::MixolydianFlat13 => &[(0, 0), (1, 2), (2, 4), (3, 5), (4, 7), (5, 8), (6, 10)],
+            Scale::LydianAugmented => &[(0, 0), (1, 2), (2, 4), (3, 6), (4, 8), (5, 9), (6, 11)],
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Scale::Ionian => "Ionian",
+            Scale::Dorian => "Dorian",
+            Scale::Mixolydian => "Mixolydian",
+            Scale::Aeolian => "Aeolian",
+            Scale::Lydian => "Lydian",
+            Scale::Phrygian => "Phrygian",
+            Scale::Locrian => "Locrian",
+            Scale::LocrianNatural2 => "Locrian ♮2",
+            Scale::MelodicMinor => "melodic minor",
+            Scale::LydianDominant => "Lydian dominant",
+            Scale::Altered => "altered",
+            Scale::HalfWholeDiminished => "half-whole diminished",
+            Scale::WholeHalfDiminished => "whole-half diminished",
+            Scale::WholeTone => "whole tone",
+            Scale::HarmonicMinor => "harmonic minor",
+            Scale::PhrygianDominant => "Phrygian dominant",
+            Scale::MixolydianFlat13 => "Mixolydian ♭13",
+            Scale::LydianAugmented => "Lydian augmented",
+        }
+    }
+
+    /// Pitches of this scale starting on `root`
+    pub fn pitches(&self, root: Pitch) -> Vec<Pitch> {
+        self.intervals()
+            .iter()
+            .map(|&(steps, semitones)| root.up(steps, semitones))
+            .collect()
+    }
+}
+
+/// Scale compatible with a chord, with the scale tones outside the chord
+/// split into available tensions and avoid notes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChordScale {
+    pub root: Pitch,
+    pub scale: Scale,
+    pub tensions: Vec<ChordTone>,
+    pub avoid: Vec<ChordTone>,
+}
+
+impl ChordScale {
+    /// E.g. "D Dorian"
+    pub fn name(&self) -> String {
+        format!(
+            "{} {}",
+            self.root.name_in(NamingStyle::English),
+            self.scale.name()
+        )
+    }
+
+    pub fn pitches(&self) -> Vec<Pitch> {
+        self.scale.pitches(self.root)
+    }
+}
+
+fn chord_scale(slots: &Slots, root: Pitch, scale: Scale) -> Option<ChordScale> {
+    let pitches = scale.pitches(root);
+    let in_scale = |pitch: &Pitch| pitches.iter().any(|p| p.id() == pitch.id());
+    if !slots.iter().flatten().all(in_scale) {
+        return None;
+    }
+    // Root, third, fifth and seventh; a tone a semitone above one of them clashes
+    let core: Vec<usize> = Slot::ALL
+        .iter()
+        .zip(slots)
+        .filter(|(slot, _)| slot.index() <= Slot::M7.index())
+        .filter_map(|(_, pitch)| pitch.map(|p| p.id()))
+        .collect();
+    let dominant = slots[Slot::M3.index()].is_some() && slots[Slot::m7.index()].is_some();
+    let (mut tensions, mut avoid) = (vec![], vec![]);
+    for (&interval, pitch) in scale.intervals().iter().zip(pitches.iter()) {
+        if slots.iter().flatten().any(|p| p.id() == pitch.id()) {
+            continue;
+        }
+        let tone = ChordTone {
+            slot: Slot::of(interval).expect("every scale interval has a slot"),
+            pitch: *pitch,
+        };
+        let clashes = core.contains(&((pitch.id() + 11) % 12));
+        // ♭9 and ♭13 are characteristic tensions of a dominant chord, not clashes
+        if clashes && !(dominant && matches!(tone.slot, Slot::m9 | Slot::m13)) {
+            avoid.push(tone);
+        } else {
+            tensions.push(tone);
+        }
+    }
+    Some(ChordScale {
+        root,
+        scale,
+        tensions,
+        avoid,
+    })
+}
+
+/// Scales containing every chord tone, most common first. With `key`, the
+/// scales sharing the most notes with the key come first, so Dm7 in C major
+/// is D Dorian rather than D Aeolian
+pub fn chord_scales(slots: &Slots, root: Pitch, key: Option<&[Pitch]>) -> Vec<ChordScale> {
+    let mut scales: Vec<ChordScale> = Scale::ALL
+        .iter()
+        .filter_map(|scale| chord_scale(slots, root, *scale))
+        .collect();
+    if let Some(key) = key {
+        // Stable sort keeps the common-first order among equally diatonic scales
+        scales.sort_by_key(|scale| {
+            let in_key = |p: &Pitch| key.iter().any(|k| k.id() == p.id());
+            scale.pitches().iter().filter(|p| !in_key(p)).count()
+        });
+    }
+    scales
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::key::*;
+    use crate::symbol::parse_chord;
+    use test_case::test_case;
+
+    fn names(tones: &[ChordTone]) -> Vec<String> {
+        tones
+            .iter()
+            .map(|t| format!("{} {}", t.function(), t.pitch.name_in(NamingStyle::English)))
+            .collect()
+    }
+
+    #[test_case("Dm7", "D Dorian")]
+    #[test_case("Cmaj7", "C Ionian")]
+    #[test_case("G7", "G Mixolydian")]
+    #[test_case("G7alt", "G altered")]
+    #[test_case("Bm7♭5", "B Locrian")]
+    #[test_case("C7♯11", "C Lydian dominant")]
+    #[test_case("Cmaj7♯11", "C Lydian")]
+    #[test_case("C7♭9", "C half-whole diminished")]
+    #[test_case("Cdim7", "C whole-half diminished")]
+    #[test_case("Caug7", "C whole tone")]
+    #[test_case("CmM7", "C melodic minor")]
+    fn test_first_scale(chord: &str, scale: &str) {
+        let chord = parse_chord(chord).unwrap();
+        assert_eq!(chord.scales()[0].name(), scale);
+    }
+
+    #[test]
+    fn test_scales_in_key() {
+        let em7 = parse_chord("Em7").unwrap();
+        assert_eq!(em7.scales()[0].name(), "E Dorian");
+        assert_eq!(
+            em7.scales_in(&<MajorKey<C>>::default())[0].name(),
+            "E Phrygian"
+        );
+        assert_eq!(
+            em7.scales_in(&<MajorKey<D>>::default())[0].name(),
+            "E Dorian"
+        );
+        assert_eq!(
+            em7.scales_in(&<MinorKey<E>>::default())[0].name(),
+            "E Aeolian"
+        );
+        assert_eq!(
+            <MinorSeventh<D>>::default().scales_in(&<MajorKey<C>>::default())[0].name(),
+            "D Dorian"
+        );
+        assert_eq!(
+            <Major<F>>::default().scales_in(&<MajorKey<C>>::default())[0].name(),
+            "F Lydian"
+        );
+    }
+
+    #[test]
+    fn test_modes() {
+        assert_eq!(<MajorKey<G> as Key>::Mode::default().scale(), Scale::Ionian);
+        assert_eq!(
+            <MinorKey<G> as Key>::Mode::default().scale(),
+            Scale::Aeolian
+        );
+        assert_eq!(Dorian.key_name("D", NamingStyle::English), "D Dorian");
+        assert_eq!(Lydian.key_name("F", NamingStyle::German), "F-Lydisch");
+        assert_eq!(
+            Scale::Dorian.pitches(Pitch::new(1, 0)),
+            <MajorKey<C>>::default()
+                .pitches()
+                .iter()
+                .cycle()
+                .skip(1)
+                .take(7)
+                .copied()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_tensions_and_avoid_notes() {
+        let g7 = &<Seventh<G>>::default().scales()[0];
+        assert_eq!(names(&g7.tensions), ["9 A", "13 E"]);
+        assert_eq!(names(&g7.avoid), ["11 C"]);
+
+        let cmaj7 = &parse_chord("Cmaj7").unwrap().scales()[0];
+        assert_eq!(names(&cmaj7.tensions), ["9 D", "13 A"]);
+        assert_eq!(names(&cmaj7.avoid), ["11 F"]);
+
+        let em7 = &parse_chord("Em7")
+            .unwrap()
+            .scales_in(&<MajorKey<C>>::default())[0];
+        assert_eq!(names(&em7.tensions), ["11 A"]);
+        assert_eq!(names(&em7.avoid), ["♭9 F", "♭13 C"]);
+
+        let g7b9 = &parse_chord("G7")
+            .unwrap()
+            .scales_in(&<MinorKey<C>>::default())[0];
+        assert_eq!(g7b9.name(), "G Phrygian dominant");
+        assert_eq!(names(&g7b9.tensions), ["♭9 A♭", "♭13 E♭"]);
+        assert_eq!(names(&g7b9.avoid), ["11 C"]);
+
+        let alt = &parse_chord("G7alt").unwrap().scales()[0];
+        assert!(alt.tensions.is_empty() && alt.avoid.is_empty());
+    }
+}
@@ -0,0 +1,544 @@
+use crate::chord::*;
+use crate::note::*;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseChordError {
+    Empty,
+    /// The symbol does not start with a note name
+    InvalidRoot(String),
+    /// The text after `/` is not a note name
+    InvalidBass(String),
+    /// Part of the symbol after the root matched no quality or modifier
+    UnknownSuffix(String),
+    /// A polychord has no `|` between its upper and lower chords
+    MissingPolyBar(String),
+}
+
+impl Display for ParseChordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseChordError::Empty => write!(f, "empty chord symbol"),
+            ParseChordError::InvalidRoot(s) => write!(f, "invalid root note: {s:?}"),
+            ParseChordError::InvalidBass(s) => write!(f, "invalid bass note: {s:?}"),
+            ParseChordError::UnknownSuffix(s) => write!(f, "unknown chord suffix: {s:?}"),
+            ParseChordError::MissingPolyBar(s) => write!(f, "no `|` in polychord: {s:?}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseChordError {}
+
+/// Spellings accepted for each quality, after `#` and `b` are read as `♯` and `♭`
+const QUALITIES: &[(&str, Quality)] = &[
+    ("", Quality::Major),
+    ("M", Quality::Major),
+    ("maj", Quality::Major),
+    ("m", Quality::Minor),
+    ("min", Quality::Minor),
+    ("-", Quality::Minor),
+    ("aug", Quality::Aug),
+    ("+", Quality::Aug),
+    ("dim", Quality::Dim),
+    ("°", Quality::Dim),
+    ("o", Quality::Dim),
+    ("7", Quality::Seventh),
+    ("maj7", Quality::MajorSeventh),
+    ("Maj7", Quality::MajorSeventh),
+    ("M7", Quality::MajorSeventh),
+    ("Δ", Quality::MajorSeventh),
+    ("Δ7", Quality::MajorSeventh),
+    ("m7", Quality::MinorSeventh),
+    ("min7", Quality::MinorSeventh),
+    ("-7", Quality::MinorSeventh),
+    ("m7♭5", Quality::HalfDiminished),
+    ("-7♭5", Quality::HalfDiminished),
+    ("ø", Quality::HalfDiminished),
+    ("ø7", Quality::HalfDiminished),
+    ("dim7", Quality::DiminishedSeventh),
+    ("°7", Quality::DiminishedSeventh),
+    ("o7", Quality::DiminishedSeventh),
+    ("mM7", Quality::MinorMajorSeventh),
+    ("mmaj7", Quality::MinorMajorSeventh),
+    ("m(maj7)", Quality::MinorMajorSeventh),
+    ("-Δ7", Quality::MinorMajorSeventh),
+    ("aug7", Quality::AugmentedSeventh),
+    ("+7", Quality::AugmentedSeventh),
+    ("augmaj7", Quality::AugmentedMajorSeventh),
+    ("+maj7", Quality::AugmentedMajorSeventh),
+    ("6", Quality::Sixth),
+    ("m6", Quality::MinorSixth),
+    ("-6", Quality::MinorSixth),
+    ("6/9", Quality::SixNine),
+    ("69", Quality::SixNine),
+    ("9", Quality::Ninth),
+    ("maj9", Quality::MajorNinth),
+    ("Maj9", Quality::MajorNinth),
+    ("M9", Quality::MajorNinth),
+    ("Δ9", Quality::MajorNinth),
+    ("m9", Quality::MinorNinth),
+    ("-9", Quality::MinorNinth),
+    ("11", Quality::Eleventh),
+    ("m11", Quality::MinorEleventh),
+    ("-11", Quality::MinorEleventh),
+    ("13", Quality::Thirteenth),
+    ("maj13", Quality::MajorThirteenth),
+    ("Maj13", Quality::MajorThirteenth),
+    ("M13", Quality::MajorThirteenth),
+    ("Δ13", Quality::MajorThirteenth),
+    ("m13", Quality::MinorThirteenth),
+    ("-13", Quality::MinorThirteenth),
+    ("7♭9", Quality::SeventhFlatNinth),
+    ("7♯9", Quality::SeventhSharpNinth),
+    ("7♯11", Quality::SeventhSharpEleventh),
+    ("7♭13", Quality::SeventhFlatThirteenth),
+    ("7alt", Quality::Altered),
+    ("alt", Quality::Altered),
+    ("It+6", Quality::ItalianSixth),
+    ("Fr+6", Quality::FrenchSixth),
+    ("Ger+6", Quality::GermanSixth),
+    ("5", Quality::Power),
+    ("quartal", Quality::Quartal),
+    ("SoWhat", Quality::SoWhat),
+    ("quintal", Quality::Quintal),
+    ("cluster", Quality::Cluster),
+];
+
+const MODIFIERS: &[(&str, Modifier)] = &[
+    ("sus2", Modifier::Sus2),
+    ("sus4", Modifier::Sus4),
+    ("sus", Modifier::Sus4),
+    ("omit1", Modifier::Omit1),
+    ("omit3", Modifier::Omit3),
+    ("omit5", Modifier::Omit5),
+    ("no3", Modifier::Omit3),
+    ("no5", Modifier::Omit5),
+    ("add2", Modifier::Add2),
+    ("add4", Modifier::Add4),
+    ("add6", Modifier::Add6),
+    ("add9", Modifier::Add9),
+    ("(add9)", Modifier::Add9),
+    // Only kept after a 6, as in "m6/9", since other slashes start a bass note
+    ("/9", Modifier::Add9),
+    ("add11", Modifier::Add11),
+    ("♭5", Modifier::Flat5),
+    ("♯5", Modifier::Sharp5),
+    ("♭9", Modifier::Flat9),
+    ("♯9", Modifier::Sharp9),
+    ("♯11", Modifier::Sharp11),
+    ("♭13", Modifier::Flat13),
+    ("(♭5)", Modifier::Flat5),
+    ("(♯5)", Modifier::Sharp5),
+    ("(♭9)", Modifier::Flat9),
+    ("(♯9)", Modifier::Sharp9),
+    ("(♯11)", Modifier::Sharp11),
+    ("(♭13)", Modifier::Flat13),
+];
+
+/// Parse a note name such as "C", "F#" or "B♭", returning the rest of the input
+fn parse_pitch(s: &str) -> Option<(Pitch, &str)> {
+    let mut chars = s.chars();
+    let letter = "CDEFGAB".find(chars.next()?)?;
+    let mut accidental = 0;
+    let mut rest = chars.as_str();
+    loop {
+        let mut chars = rest.chars();
+        match chars.next() {
+            Some('#' | '♯') => accidental += 1,
+            Some('b' | '♭') => accidental -= 1,
+            _ => break,
+        }
+        rest = chars.as_str();
+    }
+    // `Note::name` pads accidentals with a space, as in "C♯ m"
+    Some((Pitch::new(letter, accidental), rest.trim_start()))
+}
+
+/// The rest of `s` after `spelling`, reading `#` and `b` in `s` as `♯` and `♭`
+fn strip_spelling<'a>(s: &'a str, spelling: &str) -> Option<&'a str> {
+    let mut chars = s.chars();
+    for expected in spelling.chars() {
+        let c = match chars.next()? {
+            '#' => '♯',
+            'b' => '♭',
+            c => c,
+        };
+        if c != expected {
+            return None;
+        }
+    }
+    Some(chars.as_str())
+}
+
+/// Spellings in `table` that start `s`, longest first
+fn prefixes<'a, T: Copy>(table: &[(&str, T)], s: &'a str) -> Vec<(T, &'a str)> {
+    let mut matches = table
+        .iter()
+        .filter_map(|(spelling, t)| Some((spelling.len(), *t, strip_spelling(s, spelling)?)))
+        .collect::<Vec<_>>();
+    matches.sort_by_key(|(len, _, _)| std::cmp::Reverse(*len));
+    matches.into_iter().map(|(_, t, rest)| (t, rest)).collect()
+}
+
+fn parse_modifiers(mut rest: &str) -> Result<Vec<Modifier>, ParseChordError> {
+    let mut modifiers = vec![];
+    while !rest.is_empty() {
+        let (modifier, next) = *prefixes(MODIFIERS, rest)
+            .first()
+            .ok_or_else(|| ParseChordError::UnknownSuffix(rest.into()))?;
+        modifiers.push(modifier);
+        rest = next;
+    }
+    Ok(modifiers)
+}
+
+/// Parse a chord symbol such as "C#m7b5/G", "F7sus4" or "Cadd9omit5"
+pub fn parse_chord(symbol: &str) -> Result<ChordValue, ParseChordError> {
+    let symbol = symbol.trim();
+    if symbol.is_empty() {
+        return Err(ParseChordError::Empty);
+    }
+    let (root, rest) =
+        parse_pitch(symbol).ok_or_else(|| ParseChordError::InvalidRoot(symbol.into()))?;
+
+    // "6/9" also contains a slash, so only a trailing note name counts as the bass
+    let (rest, bass) = match rest.rsplit_once('/') {
+        Some((chord, bass)) if chord.ends_with('6') && bass.starts_with('9') => (rest, None),
+        Some((chord, bass)) => match parse_pitch(bass) {
+            Some((pitch, "")) => (chord, Some(pitch)),
+            _ => return Err(ParseChordError::InvalidBass(bass.into())),
+        },
+        None => (rest, None),
+    };
+
+    // "o" is a diminished triad but also starts "omit", so shorter qualities are tried too.
+    // The empty spelling of `Major` always matches, and the longest match names the error.
+    let attempts = prefixes(QUALITIES, rest)
+        .into_iter()
+        .map(|(quality, rest)| Ok((quality, parse_modifiers(rest)?)))
+        .collect::<Vec<Result<_, ParseChordError>>>();
+    let (quality, modifiers) = attempts
+        .iter()
+        .find(|attempt| attempt.is_ok())
+        .unwrap_or(&attempts[0])
+        .clone()?;
+    let mut chord = ChordValue::new(root, quality);
+    for modifier in modifiers {
+        chord = chord.with(modifier);
+    }
+    if let Some(bass) = bass.filter(|b| *b != root) {
+        chord = chord.over(bass);
+    }
+    Ok(chord)
+}
+
+impl FromStr for ChordValue {
+    type Err = ParseChordError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_chord(s)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolStyle {
+    /// CΔ7, C-7, Cø7, C°7
+    Jazz,
+    /// Cmaj7, C-7, C-7(♭5), Co7, C7(♭9)
+    RealBook,
+    /// C#m7b5, using only ASCII characters
+    Ascii,
+    /// C: major triad
+    Classical,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Markup {
+    Plain,
+    /// Extensions wrapped in `<sup>`
+    Html,
+    /// Extensions as a LaTeX superscript
+    Latex,
+}
+
+/// Quality written in each style: Jazz, RealBook, Ascii, Classical
+fn quality_symbol(quality: Quality, style: SymbolStyle) -> &'static str {
+    let symbols = match quality {
+        Quality::Major => ["", "", "", "major triad"],
+        Quality::Minor => ["-", "-", "m", "minor triad"],
+        Quality::Aug => ["+", "+", "aug", "augmented triad"],
+        Quality::Dim => ["°", "o", "dim", "diminished triad"],
+        Quality::Seventh => ["7", "7", "7", "dominant seventh"],
+        Quality::MajorSeventh => ["Δ7", "maj7", "maj7", "major seventh"],
+        Quality::MinorSeventh => ["-7", "-7", "m7", "minor seventh"],
+        Quality::HalfDiminished => ["ø7", "-7(♭5)", "m7b5", "half-diminished seventh"],
+        Quality::DiminishedSeventh => ["°7", "o7", "dim7", "diminished seventh"],
+        Quality::MinorMajorSeventh => ["-Δ7", "-(maj7)", "mM7", "minor-major seventh"],
+        Quality::AugmentedSeventh => ["+7", "7(♯5)", "aug7", "augmented seventh"],
+        Quality::AugmentedMajorSeventh => ["+Δ7", "maj7(♯5)", "augmaj7", "augmented major seventh"],
+        Quality::Sixth => ["6", "6", "6", "added sixth"],
+        Quality::MinorSixth => ["-6", "-6", "m6", "minor added sixth"],
+        Quality::SixNine => ["6/9", "6/9", "6/9", "added sixth and ninth"],
+        Quality::Ninth => ["9", "9", "9", "dominant ninth"],
+        Quality::MajorNinth => ["Δ9", "maj9", "maj9", "major ninth"],
+        Quality::MinorNinth => ["-9", "-9", "m9", "minor ninth"],
+        Quality::Eleventh => ["11", "11", "11", "dominant eleventh"],
+        Quality::MinorEleventh => ["-11", "-11", "m11", "minor eleventh"],
+        Quality::Thirteenth => ["13", "13", "13", "dominant thirteenth"],
+        Quality::MajorThirteenth => ["Δ13", "maj13", "maj13", "major thirteenth"],
+        Quality::MinorThirteenth => ["-13", "-13", "m13", "minor thirteenth"],
+        Quality::SeventhFlatNinth => ["7♭9", "7(♭9)", "7b9", "dominant seventh, flat ninth"],
+        Quality::SeventhSharpNinth => ["7♯9", "7(♯9)", "7#9", "dominant seventh, sharp ninth"],
+        Quality::SeventhSharpEleventh => {
+            ["7♯11", "7(♯11)", "7#11", "dominant seventh, sharp eleventh"]
+        }
+        Quality::SeventhFlatThirteenth => [
+            "7♭13",
+            "7(♭13)",
+            "7b13",
+            "dominant seventh, flat thirteenth",
+        ],
+        Quality::Altered => ["7alt", "7alt", "7alt", "altered dominant seventh"],
+        Quality::ItalianSixth => ["It+6", "It+6", "It+6", "Italian augmented sixth"],
+        Quality::FrenchSixth => ["Fr+6", "Fr+6", "Fr+6", "French augmented sixth"],
+        Quality::GermanSixth => ["Ger+6", "Ger+6", "Ger+6", "German augmented sixth"],
+        Quality::Power => ["5", "5", "5", "power chord"],
+        Quality::Quartal => ["quartal", "quartal", "quartal", "quartal chord"],
+        Quality::SoWhat => ["SoWhat", "SoWhat", "SoWhat", "So What chord"],
+        Quality::Quintal => ["quintal", "quintal", "quintal", "quintal chord"],
+        Quality::Cluster => ["cluster", "cluster", "cluster", "tone cluster"],
+    };
+    symbols[style as usize]
+}
+
+/// Modifier written in each style: Jazz, RealBook, Ascii, Classical
+fn modifier_symbol(modifier: Modifier, style: SymbolStyle) -> &'static str {
+    let symbols = match modifier {
+        Modifier::Sus2 => ["sus2", "sus2", "sus2", "suspended second"],
+        Modifier::Sus4 => ["sus4", "sus", "sus4", "suspended fourth"],
+        Modifier::Omit1 => ["omit1", "(no root)", "omit1", "without root"],
+        Modifier::Omit3 => ["omit3", "(no 3)", "no3", "without third"],
+        Modifier::Omit5 => ["omit5", "(no 5)", "no5", "without fifth"],
+        Modifier::Add2 => ["add2", "(add 2)", "add2", "added second"],
+        Modifier::Add4 => ["add4", "(add 4)", "add4", "added fourth"],
+        Modifier::Add6 => ["add6", "(add 6)", "add6", "added sixth"],
+        Modifier::Add9 => ["add9", "(add 9)", "add9", "added ninth"],
+        Modifier::Add11 => ["add11", "(add 11)", "add11", "added eleventh"],
+        Modifier::Flat5 => ["♭5", "(♭5)", "b5", "flat fifth"],
+        Modifier::Sharp5 => ["♯5", "(♯5)", "#5", "sharp fifth"],
+        Modifier::Flat9 => ["♭9", "(♭9)", "b9", "flat ninth"],
+        Modifier::Sharp9 => ["♯9", "(♯9)", "#9", "sharp ninth"],
+        Modifier::Sharp11 => ["♯11", "(♯11)", "#11", "sharp eleventh"],
+        Modifier::Flat13 => ["♭13", "(♭13)", "b13", "flat thirteenth"],
+    };
+    symbols[style as usize]
+}
+
+fn root_symbol(pitch: Pitch, style: SymbolStyle) -> String {
+    match style {
+        SymbolStyle::Ascii => pitch.name().trim_end().replace('♯', "#").replace('♭', "b"),
+        _ => pitch.name_in(NamingStyle::English),
+    }
+}
+
+/// Chord symbol split into the parts typeset differently
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolParts {
+    pub root: String,
+    /// Written on the baseline, such as "-" or "Δ"
+    pub quality: String,
+    /// Written raised, such as "7♭9sus4"
+    pub extensions: String,
+    pub bass: Option<String>,
+}
+
+impl SymbolParts {
+    pub fn new(chord: &ChordValue, style: SymbolStyle) -> Self {
+        let quality = quality_symbol(chord.quality, style);
+        let split = quality
+            .find(|c: char| c.is_ascii_digit() || c == '(')
+            .unwrap_or(quality.len());
+        let mut extensions = quality[split..].to_string();
+        for modifier in &chord.modifiers {
+            let symbol = modifier_symbol(*modifier, style);
+            // An alteration right after the root would read as its accidental
+            if quality.is_empty()
+                && extensions.is_empty()
+                && symbol.starts_with(['♭', '♯', 'b', '#'])
+            {
+                extensions = format!("({symbol})");
+            } else {
+                extensions += symbol;
+            }
+        }
+        Self {
+            root: root_symbol(chord.root, style),
+            quality: quality[..split].into(),
+            extensions,
+            bass: chord.bass.map(|b| root_symbol(b, style)),
+        }
+    }
+
+    pub fn render(&self, markup: Markup) -> String {
+        let extensions = match markup {
+            _ if self.extensions.is_empty() => "".into(),
+            Markup::Plain => self.extensions.clone(),
+            Markup::Html => format!("<sup>{}</sup>", self.extensions),
+            Markup::Latex => format!("^{{{}}}", self.extensions),
+        };
+        let bass = match &self.bass {
+            Some(bass) => format!("/{bass}"),
+            None => "".into(),
+        };
+        format!("{}{}{}{}", self.root, self.quality, extensions, bass)
+    }
+}
+
+/// Chord symbol in `style`, e.g. "Cø7" in `SymbolStyle::Jazz`
+pub fn format_chord(chord: &ChordValue, style: SymbolStyle) -> String {
+    if style == SymbolStyle::Classical {
+        return format_classical(chord);
+    }
+    SymbolParts::new(chord, style).render(Markup::Plain)
+}
+
+/// Chord symbol in `style` with its extensions raised for typesetting
+pub fn format_chord_markup(chord: &ChordValue, style: SymbolStyle, markup: Markup) -> String {
+    if style == SymbolStyle::Classical {
+        return format_classical(chord);
+    }
+    SymbolParts::new(chord, style).render(markup)
+}
+
+fn format_classical(chord: &ChordValue) -> String {
+    let style = SymbolStyle::Classical;
+    let mut description = quality_symbol(chord.quality, style).to_string();
+    for modifier in &chord.modifiers {
+        description += &format!(", {}", modifier_symbol(*modifier, style));
+    }
+    if let Some(bass) = chord.bass {
+        description += &format!(", {} in the bass", root_symbol(bass, style));
+    }
+    format!("{}: {}", root_symbol(chord.root, style), description)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("C", <Major<C>>::default())]
+    #[test_case("A-7", <MinorSeventh<A>>::default())]
+    #[test_case("C#m7b5", <HalfDiminished<Sharp<C>>>::default())]
+    #[test_case("C#m7b5/G", <Slash<HalfDiminished<Sharp<C>>, G>>::default())]
+    #[test_case("Eø7", <HalfDiminished<E>>::default())]
+    #[test_case("F7sus4", <Sus4<Seventh<F>>>::default())]
+    #[test_case("Cmaj7sus4omit5", <Omit5<Sus4<MajorSeventh<C>>>>::default())]
+    #[test_case("Bbdim7", <DiminishedSeventh<Flat<B>>>::default())]
+    #[test_case("G7b9", <SeventhFlatNinth<G>>::default())]
+    #[test_case("C6/9", <SixNine<C>>::default())]
+    #[test_case("Am7/G", <Slash<MinorSeventh<A>, G>>::default())]
+    #[test_case("Ebsus2", <Sus2<Major<Flat<E>>>>::default())]
+    #[test_case("Caug", <Aug<C>>::default())]
+    #[test_case("Bdim", <Dim<B>>::default())]
+    #[test_case("CmM7", <MinorMajorSeventh<C>>::default())]
+    #[test_case("Caugmaj7", <AugmentedMajorSeventh<C>>::default())]
+    #[test_case("Abm6", <MinorSixth<Flat<A>>>::default())]
+    #[test_case("D13", <Thirteenth<D>>::default())]
+    #[test_case("Gomit1", <Omit1<Major<G>>>::default())]
+    #[test_case("C/G", <Inversion<Major<C>, Second>>::default())]
+    #[test_case("Cadd9", <Add9<Major<C>>>::default())]
+    #[test_case("Cmadd9omit5", <Omit5<Add9<Minor<C>>>>::default())]
+    #[test_case("Dsus4add2", <Add2<Sus4<Major<D>>>>::default())]
+    #[test_case("C7b5", <Flat5<Seventh<C>>>::default())]
+    #[test_case("G7#9b13", <Flat13<SeventhSharpNinth<G>>>::default())]
+    #[test_case("Bbmaj9#11", <Sharp11<MajorNinth<Flat<B>>>>::default())]
+    #[test_case("G7alt", <Altered<G>>::default())]
+    #[test_case("CMaj7", <MajorSeventh<C>>::default())]
+    #[test_case("C(#5)", <Sharp5<Major<C>>>::default())]
+    fn test_parse_agrees_with_type(symbol: &str, chord: impl Chord) {
+        let parsed = parse_chord(symbol).unwrap();
+        assert_eq!(parsed.name(), chord.name());
+        assert_eq!(parsed.slots(), chord.slots());
+        assert_eq!(parsed.notes(), chord.notes());
+        assert_eq!(parsed, chord.value());
+    }
+
+    #[test_case("Bbmaj9#11", "B♭ maj9♯11", "B♭  D   F   A   C   E  ")]
+    #[test_case("Cadd9omit5", "Cadd9omit5", "C   E   D  ")]
+    #[test_case("F#-9", "F♯ m9", "F♯  A   C♯  E   G♯ ")]
+    #[test_case("Comit3", "Comit3", "C   G  ")]
+    #[test_case("Cm6/9", "Cm6add9", "C   E♭  G   A   D  ")]
+    fn test_parse(symbol: &str, name: &str, notes: &str) {
+        let parsed: ChordValue = symbol.parse().unwrap();
+        assert_eq!(parsed.name(), name);
+        assert_eq!(parsed.notes(), notes);
+    }
+
+    #[test_case("", ParseChordError::Empty)]
+    #[test_case("H7", ParseChordError::InvalidRoot("H7".into()))]
+    #[test_case("C/X", ParseChordError::InvalidBass("X".into()))]
+    #[test_case("Cm7xyz", ParseChordError::UnknownSuffix("xyz".into()))]
+    #[test_case("Cmaj7sus4foo", ParseChordError::UnknownSuffix("foo".into()))]
+    #[test_case("Cxyzb", ParseChordError::UnknownSuffix("xyzb".into()))]
+    #[test_case("C7/9", ParseChordError::InvalidBass("9".into()))]
+    fn test_parse_error(symbol: &str, expected: ParseChordError) {
+        assert_eq!(parse_chord(symbol), Err(expected));
+    }
+
+    #[test_case("C#m7b5", "C♯ø7", "C♯-7(♭5)", "C#m7b5", "C♯: half-diminished seventh")]
+    #[test_case("Cmaj7", "CΔ7", "Cmaj7", "Cmaj7", "C: major seventh"; "major seventh")]
+    #[test_case("Cm7", "C-7", "C-7", "Cm7", "C: minor seventh")]
+    #[test_case("Cdim7", "C°7", "Co7", "Cdim7", "C: diminished seventh")]
+    #[test_case("C", "C", "C", "C", "C: major triad")]
+    #[test_case(
+        "Bb7b9",
+        "B♭7♭9",
+        "B♭7(♭9)",
+        "Bb7b9",
+        "B♭: dominant seventh, flat ninth"
+    )]
+    #[test_case(
+        "F7sus4",
+        "F7sus4",
+        "F7sus",
+        "F7sus4",
+        "F: dominant seventh, suspended fourth"
+    )]
+    #[test_case("Am7/G", "A-7/G", "A-7/G", "Am7/G", "A: minor seventh, G in the bass")]
+    #[test_case("C(#5)", "C(♯5)", "C(♯5)", "C(#5)", "C: major triad, sharp fifth")]
+    fn test_format_chord(symbol: &str, jazz: &str, real_book: &str, ascii: &str, classical: &str) {
+        let chord = parse_chord(symbol).unwrap();
+        assert_eq!(format_chord(&chord, SymbolStyle::Jazz), jazz);
+        assert_eq!(format_chord(&chord, SymbolStyle::RealBook), real_book);
+        assert_eq!(format_chord(&chord, SymbolStyle::Ascii), ascii);
+        assert_eq!(format_chord(&chord, SymbolStyle::Classical), classical);
+    }
+
+    #[test]
+    fn test_format_chord_markup() {
+        let chord = parse_chord("Bbm7b5/E").unwrap();
+        assert_eq!(
+            format_chord_markup(&chord, SymbolStyle::Jazz, Markup::Html),
+            "B♭ø<sup>7</sup>/E"
+        );
+        assert_eq!(
+            format_chord_markup(&chord, SymbolStyle::RealBook, Markup::Latex),
+            "B♭-^{7(♭5)}/E"
+        );
+        let chord = parse_chord("C").unwrap();
+        assert_eq!(
+            format_chord_markup(&chord, SymbolStyle::Jazz, Markup::Html),
+            "C"
+        );
+    }
+
+    #[test]
+    fn test_format_type_level_chord() {
+        let chord = <Sus4<Seventh<Sharp<F>>>>::default().value();
+        assert_eq!(chord.slots(), <Sus4<Seventh<Sharp<F>>>>::default().slots());
+        assert_eq!(format_chord(&chord, SymbolStyle::Ascii), "F#7sus4");
+        let chord = <Inversion<MinorSeventh<D>, Third>>::default().value();
+        assert_eq!(format_chord(&chord, SymbolStyle::Jazz), "D-7/C");
+    }
+}